@@ -1,8 +1,24 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 use linear_algebra_ml::matrix::matrix::Matrix;
 use linear_algebra_ml::matrix::ops;
 
+// the i-j-k triple loop ops::mul used before the blocked GEMM, kept as the
+// baseline the speed-up is measured against
+fn naive_mul(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut result = Matrix::zeros(a.rows, b.cols);
+    for i in 0..a.rows {
+        for j in 0..b.cols {
+            let mut sum = 0.0;
+            for k in 0..a.cols {
+                sum += a.get(i, k) * b.get(k, j);
+            }
+            result.set(i, j, sum);
+        }
+    }
+    result
+}
+
 fn bench_mul(c: &mut Criterion) {
     let a = Matrix::new(100, 100, vec![1.0; 100 * 100]);
     let b = Matrix::new(100, 100, vec![2.0; 100 * 100]);
@@ -10,6 +26,9 @@ fn bench_mul(c: &mut Criterion) {
     c.bench_function("matrix multiplication 100x100", |bencher| {
         bencher.iter(|| ops::mul(&a, &b).unwrap())
     });
+    c.bench_function("naive triple loop 100x100", |bencher| {
+        bencher.iter(|| naive_mul(black_box(&a), black_box(&b)))
+    });
 }
 
 criterion_group!(benches, bench_mul);
@@ -6,19 +6,23 @@ use linear_algebra_ml::Dense;
 use linear_algebra_ml::matrix::ops;
 
 fn main() {
-    let a = Dense!(3, 3, [ 
+    let a: Matrix = Dense!(3, 3, [ 
         2.0, 3.0, 1.0,
         4.0, 7.0, 3.0,
         6.0, 18.0, 5.0 
     ]);
 
     match decompositions::lu(&a) {
-        Ok((l, u)) => {
+        Ok((l, u, perm, _)) => {
             println!("Lower matrix:\n{}", l);
             println!("Upper matrix:\n{}", u);
             let result = ops::mul(&l, &u).unwrap();
             println!("Result:\n{}", result);
-            assert_eq!(a, result);
+            for (i, &p) in perm.iter().enumerate() {
+                for j in 0..a.cols {
+                    assert!((result.get(i, j) - a.get(p, j)).abs() < 1e-12);
+                }
+            }
         }
         Err(e) => println!("LU decomposition failed: {:?}", e),
     }
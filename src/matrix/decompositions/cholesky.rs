@@ -0,0 +1,184 @@
+use crate::matrix::decompositions::condition::{inverse_norm1_estimate, rcond_from};
+use crate::matrix::decompositions::symmetric::check_symmetric;
+use crate::matrix::decompositions::triangular::{
+    column, set_column, solve_lower, solve_lower_transpose,
+};
+use crate::matrix::matrix::Matrix;
+use crate::matrix::norms::norm_1;
+use crate::utils::error::Error;
+use crate::utils::scalar::RealField;
+
+// A = L * L^T for symmetric positive-definite A, L lower triangular
+pub fn cholesky<T: RealField>(matrix: &Matrix<T>) -> Result<Matrix<T>, Error> {
+    let n = check_symmetric(matrix, "cholesky")?;
+    let mut lower = Matrix::zeros(n, n);
+
+    for j in 0..n {
+        let mut diag = matrix.get(j, j);
+        for k in 0..j {
+            diag -= lower.get(j, k) * lower.get(j, k);
+        }
+        if diag <= T::zero() || diag.is_nan() {
+            return Err(Error::NotPositiveDefinite { pivot: j });
+        }
+        let diag = diag.sqrt();
+        lower.set(j, j, diag);
+
+        for i in (j + 1)..n {
+            let mut value = matrix.get(i, j);
+            for k in 0..j {
+                value -= lower.get(i, k) * lower.get(j, k);
+            }
+            lower.set(i, j, value / diag);
+        }
+    }
+
+    Ok(lower)
+}
+
+// Cholesky with diagonal pivoting for positive semi-definite A:
+// P^T * A * P = L * L^T, where (P^T A P)[i][j] = A[perm[i]][perm[j]].
+// Stops once the remaining diagonal falls below n * eps * max(diag(A)),
+// so only the first `rank` columns of L are non-zero. Indefinite input
+// fails with NotPositiveDefinite rather than giving a truncated factor.
+pub fn cholesky_pivoted<T: RealField>(
+    matrix: &Matrix<T>,
+) -> Result<(Matrix<T>, Vec<usize>, usize), Error> {
+    let n = check_symmetric(matrix, "cholesky_pivoted")?;
+    let mut a = matrix.to_row_major().into_owned();
+    let mut lower = Matrix::zeros(n, n);
+    let mut perm: Vec<usize> = (0..n).collect();
+
+    if let Some(pivot) = (0..n).find(|&i| a.get(i, i) < T::zero() || a.get(i, i).is_nan()) {
+        return Err(Error::NotPositiveDefinite { pivot });
+    }
+    let max_diag = (0..n).map(|i| a.get(i, i)).fold(T::zero(), T::max);
+    let tol = T::from_usize(n) * T::EPSILON * max_diag;
+
+    let mut rank = 0;
+    for k in 0..n {
+        // largest remaining diagonal of the Schur complement
+        let mut pivot = k;
+        for i in (k + 1)..n {
+            if a.get(i, i) > a.get(pivot, pivot) {
+                pivot = i;
+            }
+        }
+        if a.get(pivot, pivot) <= tol {
+            check_negligible_schur(&a, k, tol)?;
+            break;
+        }
+
+        if pivot != k {
+            swap_symmetric(&mut a, k, pivot);
+            for j in 0..k {
+                lower.data.swap(k * n + j, pivot * n + j);
+            }
+            perm.swap(k, pivot);
+        }
+
+        let diag = a.get(k, k).sqrt();
+        lower.set(k, k, diag);
+        for i in (k + 1)..n {
+            lower.set(i, k, a.get(i, k) / diag);
+        }
+        for i in (k + 1)..n {
+            for j in (k + 1)..=i {
+                let value = a.get(i, j) - lower.get(i, k) * lower.get(j, k);
+                a.set(i, j, value);
+                a.set(j, i, value);
+            }
+        }
+        rank += 1;
+    }
+
+    Ok((lower, perm, rank))
+}
+
+// the Schur complement left when the pivoting stops must vanish up to `tol`;
+// a clearly negative diagonal or a large off-diagonal means A is indefinite
+fn check_negligible_schur<T: RealField>(a: &Matrix<T>, k: usize, tol: T) -> Result<(), Error> {
+    let n = a.rows;
+    for i in k..n {
+        if a.get(i, i) < -tol || (k..i).any(|j| a.get(i, j).abs() > tol) {
+            return Err(Error::NotPositiveDefinite { pivot: k });
+        }
+    }
+    Ok(())
+}
+
+// swaps rows and columns p and q of a symmetric matrix
+pub(crate) fn swap_symmetric<T: RealField>(a: &mut Matrix<T>, p: usize, q: usize) {
+    let n = a.cols;
+    for j in 0..n {
+        a.data.swap(p * n + j, q * n + j);
+    }
+    for i in 0..a.rows {
+        a.data.swap(i * n + p, i * n + q);
+    }
+}
+
+// reusable Cholesky factorization of a symmetric positive-definite matrix
+#[derive(Debug, Clone)]
+pub struct CholeskyFactor<T = f64> {
+    lower: Matrix<T>,
+    anorm: T,
+}
+
+impl<T: RealField> CholeskyFactor<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<Self, Error> {
+        Ok(Self {
+            lower: cholesky(matrix)?,
+            anorm: norm_1(matrix),
+        })
+    }
+
+    pub fn lower(&self) -> &Matrix<T> {
+        &self.lower
+    }
+
+    // solves A * X = B via L * L^T * X = B
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        let n = self.lower.rows;
+        if b.rows != n {
+            return Err(Error::DimensionMismatch {
+                op: "cholesky solve",
+                left: self.lower.shape(),
+                right: b.shape(),
+            });
+        }
+
+        let mut x = Matrix::zeros(n, b.cols);
+        for j in 0..b.cols {
+            let mut col = column(b, j);
+            self.solve_in_place(&mut col);
+            set_column(&mut x, j, &col);
+        }
+        Ok(x)
+    }
+
+    pub fn determinant(&self) -> T {
+        (0..self.lower.rows)
+            .map(|i| self.lower.get(i, i))
+            .fold(T::one(), |det, d| det * d * d)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<T>, Error> {
+        self.solve(&Matrix::identity(self.lower.rows))
+    }
+
+    pub fn rcond(&self) -> T {
+        // A is symmetric, so the same solve serves for A^T
+        let estimate = inverse_norm1_estimate(
+            self.lower.rows,
+            |x| self.solve_in_place(x),
+            |x| self.solve_in_place(x),
+        );
+        rcond_from(self.anorm, estimate)
+    }
+
+    fn solve_in_place(&self, b: &mut [T]) {
+        solve_lower(&self.lower, b, false);
+        solve_lower_transpose(&self.lower, b, false);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::utils::scalar::RealField;
+
+const MAX_ESTIMATE_ITER: usize = 5;
+
+// Hager's estimate of ||A^-1||_1 from solves with A and A^T only, so a
+// factorization gives the condition number in O(n^2) instead of O(n^3).
+// `solve` and `solve_transpose` overwrite x with A^-1 x and A^-T x; any
+// factorization works. Higham's alternating-sign test vector (as in LAPACK's
+// xLACON) guards against the matrices that fool the plain iteration. The
+// result is a lower bound, usually within a factor of 3.
+pub fn inverse_norm1_estimate<T, F, G>(n: usize, solve: F, solve_transpose: G) -> T
+where
+    T: RealField,
+    F: Fn(&mut [T]),
+    G: Fn(&mut [T]),
+{
+    if n == 0 {
+        return T::zero();
+    }
+
+    let mut x = vec![T::one() / T::from_usize(n); n];
+    let mut estimate = T::zero();
+    for iter in 0..MAX_ESTIMATE_ITER {
+        let mut y = x.clone();
+        solve(&mut y);
+        estimate = y.iter().map(|v| v.abs()).sum();
+
+        let mut z: Vec<T> = y
+            .iter()
+            .map(|v| if *v >= T::zero() { T::one() } else { -T::one() })
+            .collect();
+        solve_transpose(&mut z);
+
+        let (mut j, mut zmax) = (0, T::zero());
+        for (i, value) in z.iter().enumerate() {
+            if value.abs() > zmax {
+                zmax = value.abs();
+                j = i;
+            }
+        }
+        let ztx: T = z.iter().zip(&x).map(|(&a, &b)| a * b).sum();
+        if iter > 0 && zmax <= ztx {
+            break;
+        }
+        x = vec![T::zero(); n];
+        x[j] = T::one();
+    }
+
+    // x_i = (-1)^i (1 + i / (n - 1))
+    let mut alt: Vec<T> = (0..n)
+        .map(|i| {
+            let magnitude = T::one() + T::from_usize(i) / T::from_usize((n - 1).max(1));
+            if i % 2 == 0 {
+                magnitude
+            } else {
+                -magnitude
+            }
+        })
+        .collect();
+    solve(&mut alt);
+    let alt_estimate =
+        T::from_f64(2.0) * alt.iter().map(|v| v.abs()).sum::<T>() / T::from_usize(3 * n);
+    estimate.max(alt_estimate)
+}
+
+// reciprocal condition number from ||A||_1 and the estimate of ||A^-1||_1
+pub(crate) fn rcond_from<T: RealField>(anorm: T, inverse_norm: T) -> T {
+    if anorm == T::zero() || !inverse_norm.is_finite() || inverse_norm == T::zero() {
+        return T::zero();
+    }
+    T::one() / (anorm * inverse_norm)
+}
@@ -0,0 +1,536 @@
+use crate::matrix::matrix::Matrix;
+use crate::utils::complex::Complex;
+use crate::utils::error::Error;
+use crate::utils::scalar::RealField;
+
+// eigenvalues of a general real square matrix, sorted by descending real part
+// (complex conjugate pairs appear with the positive imaginary part first)
+pub fn eigenvalues<T: RealField>(matrix: &Matrix<T>) -> Result<Vec<Complex<T>>, Error> {
+    let (values, _) = solve(matrix, false)?;
+    Ok(values)
+}
+
+// eigenvalues plus unit-norm eigenvectors; column j of the returned matrix is
+// the eigenvector for eigenvalue j
+#[allow(clippy::type_complexity)]
+pub fn eigen<T: RealField>(
+    matrix: &Matrix<T>,
+) -> Result<(Vec<Complex<T>>, Matrix<Complex<T>>), Error> {
+    let (values, vectors) = solve(matrix, true)?;
+    Ok((values, vectors.expect("eigenvectors were requested")))
+}
+
+#[allow(clippy::type_complexity)]
+// the kernels below are EISPACK ports and keep its index loops
+#[allow(clippy::needless_range_loop)]
+fn solve<T: RealField>(
+    matrix: &Matrix<T>,
+    want_vectors: bool,
+) -> Result<(Vec<Complex<T>>, Option<Matrix<Complex<T>>>), Error> {
+    if matrix.rows != matrix.cols {
+        return Err(Error::MatrixNotSquare {
+            op: "eigen",
+            shape: matrix.shape(),
+        });
+    }
+
+    let n = matrix.rows;
+    let mut h: Vec<Vec<T>> = (0..n).map(|i| matrix.row(i).iter().collect()).collect();
+    let mut v = vec![vec![T::zero(); n]; n];
+    let mut d = vec![T::zero(); n];
+    let mut e = vec![T::zero(); n];
+
+    hessenberg(&mut h, &mut v);
+    schur(&mut h, &mut v, &mut d, &mut e, want_vectors)?;
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| d[b].total_cmp(&d[a]).then(e[b].total_cmp(&e[a])));
+    let values: Vec<Complex<T>> = order.iter().map(|&i| Complex::new(d[i], e[i])).collect();
+
+    if !want_vectors {
+        return Ok((values, None));
+    }
+
+    // columns of V are packed as in EISPACK: a complex pair at (j, j + 1)
+    // stores the real part in column j and the imaginary part in column j + 1
+    let mut vectors = Matrix::zeros(n, n);
+    for (k, &j) in order.iter().enumerate() {
+        let (real_col, imag_col, sign) = if e[j] == T::zero() {
+            (j, None, T::one())
+        } else if e[j] > T::zero() {
+            (j, Some(j + 1), T::one())
+        } else {
+            (j - 1, Some(j), -T::one())
+        };
+        let norm = (0..n)
+            .map(|i| {
+                let x = v[i][real_col];
+                let y = imag_col.map_or(T::zero(), |c| v[i][c]);
+                x * x + y * y
+            })
+            .sum::<T>()
+            .sqrt();
+        let scale = if norm > T::zero() {
+            T::one() / norm
+        } else {
+            T::one()
+        };
+        for i in 0..n {
+            let imag = imag_col.map_or(T::zero(), |c| sign * v[i][c] * scale);
+            vectors.set(i, k, Complex::new(v[i][real_col] * scale, imag));
+        }
+    }
+
+    Ok((values, Some(vectors)))
+}
+
+// reduction to upper Hessenberg form by orthogonal similarity transforms,
+// accumulating the transformation in V
+#[allow(clippy::needless_range_loop)]
+fn hessenberg<T: RealField>(h: &mut [Vec<T>], v: &mut [Vec<T>]) {
+    let n = h.len();
+    let mut ort = vec![T::zero(); n];
+
+    for m in 1..n.saturating_sub(1) {
+        let scale: T = (m..n).map(|i| h[i][m - 1].abs()).sum();
+        if scale == T::zero() {
+            continue;
+        }
+
+        let mut norm_sq = T::zero();
+        for i in (m..n).rev() {
+            ort[i] = h[i][m - 1] / scale;
+            norm_sq += ort[i] * ort[i];
+        }
+        let mut g = norm_sq.sqrt();
+        if ort[m] > T::zero() {
+            g = -g;
+        }
+        norm_sq -= ort[m] * g;
+        ort[m] -= g;
+
+        for j in m..n {
+            let f = (m..n).rev().map(|i| ort[i] * h[i][j]).sum::<T>() / norm_sq;
+            for i in m..n {
+                h[i][j] -= f * ort[i];
+            }
+        }
+        for i in 0..n {
+            let f = (m..n).rev().map(|j| ort[j] * h[i][j]).sum::<T>() / norm_sq;
+            for j in m..n {
+                h[i][j] -= f * ort[j];
+            }
+        }
+        ort[m] *= scale;
+        h[m][m - 1] = scale * g;
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            v[i][j] = if i == j { T::one() } else { T::zero() };
+        }
+    }
+    for m in (1..n.saturating_sub(1)).rev() {
+        if h[m][m - 1] == T::zero() {
+            continue;
+        }
+        for i in (m + 1)..n {
+            ort[i] = h[i][m - 1];
+        }
+        for j in m..n {
+            let g: T = (m..n).map(|i| ort[i] * v[i][j]).sum();
+            // double division avoids possible underflow
+            let g = (g / ort[m]) / h[m][m - 1];
+            for i in m..n {
+                v[i][j] += g * ort[i];
+            }
+        }
+    }
+}
+
+// Francis double-shift QR iteration on the Hessenberg matrix down to real Schur
+// form, followed by back-substitution for the eigenvectors (EISPACK hqr2)
+#[allow(clippy::needless_range_loop)]
+fn schur<T: RealField>(
+    h: &mut [Vec<T>],
+    v: &mut [Vec<T>],
+    d: &mut [T],
+    e: &mut [T],
+    want_vectors: bool,
+) -> Result<(), Error> {
+    let nn = h.len();
+    let eps = T::EPSILON;
+    let max_iter = 30 * nn.max(10);
+    let mut exshift = T::zero();
+    let (mut p, mut q, mut r, mut s, mut z, mut w, mut x, mut y);
+
+    let mut norm = T::zero();
+    for i in 0..nn {
+        for j in i.saturating_sub(1)..nn {
+            norm += h[i][j].abs();
+        }
+    }
+
+    let mut n = nn as isize - 1;
+    let mut iter = 0;
+    while n >= 0 {
+        let nu = n as usize;
+
+        // look for a single small sub-diagonal element
+        let mut l = nu;
+        while l > 0 {
+            s = h[l - 1][l - 1].abs() + h[l][l].abs();
+            if s == T::zero() {
+                s = norm;
+            }
+            if h[l][l - 1].abs() < eps * s {
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == nu {
+            // one root found
+            h[nu][nu] += exshift;
+            d[nu] = h[nu][nu];
+            e[nu] = T::zero();
+            n -= 1;
+            iter = 0;
+        } else if l + 1 == nu {
+            // two roots found
+            w = h[nu][nu - 1] * h[nu - 1][nu];
+            p = (h[nu - 1][nu - 1] - h[nu][nu]) / T::from_f64(2.0);
+            q = p * p + w;
+            z = q.abs().sqrt();
+            h[nu][nu] += exshift;
+            h[nu - 1][nu - 1] += exshift;
+            x = h[nu][nu];
+
+            if q >= T::zero() {
+                // real pair
+                z = if p >= T::zero() { p + z } else { p - z };
+                d[nu - 1] = x + z;
+                d[nu] = d[nu - 1];
+                if z != T::zero() {
+                    d[nu] = x - w / z;
+                }
+                e[nu - 1] = T::zero();
+                e[nu] = T::zero();
+                x = h[nu][nu - 1];
+                s = x.abs() + z.abs();
+                p = x / s;
+                q = z / s;
+                r = (p * p + q * q).sqrt();
+                p /= r;
+                q /= r;
+
+                for j in (nu - 1)..nn {
+                    z = h[nu - 1][j];
+                    h[nu - 1][j] = q * z + p * h[nu][j];
+                    h[nu][j] = q * h[nu][j] - p * z;
+                }
+                for i in 0..=nu {
+                    z = h[i][nu - 1];
+                    h[i][nu - 1] = q * z + p * h[i][nu];
+                    h[i][nu] = q * h[i][nu] - p * z;
+                }
+                if want_vectors {
+                    for row in v.iter_mut() {
+                        z = row[nu - 1];
+                        row[nu - 1] = q * z + p * row[nu];
+                        row[nu] = q * row[nu] - p * z;
+                    }
+                }
+            } else {
+                // complex pair
+                d[nu - 1] = x + p;
+                d[nu] = x + p;
+                e[nu - 1] = z;
+                e[nu] = -z;
+            }
+            n -= 2;
+            iter = 0;
+        } else {
+            // no convergence yet, form the shift
+            x = h[nu][nu];
+            y = h[nu - 1][nu - 1];
+            w = h[nu][nu - 1] * h[nu - 1][nu];
+
+            // Wilkinson's original ad hoc shift
+            if iter == 10 {
+                exshift += x;
+                for i in 0..=nu {
+                    h[i][i] -= x;
+                }
+                s = h[nu][nu - 1].abs() + h[nu - 1][nu - 2].abs();
+                x = T::from_f64(0.75) * s;
+                y = x;
+                w = -T::from_f64(0.4375) * s * s;
+            }
+
+            // MATLAB's ad hoc shift
+            if iter == 30 {
+                s = (y - x) / T::from_f64(2.0);
+                s = s * s + w;
+                if s > T::zero() {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / T::from_f64(2.0) + s);
+                    for i in 0..=nu {
+                        h[i][i] -= s;
+                    }
+                    exshift += s;
+                    x = T::from_f64(0.964);
+                    y = x;
+                    w = x;
+                }
+            }
+
+            iter += 1;
+            if iter > max_iter {
+                return Err(Error::NotConverged {
+                    op: "eigen",
+                    iters: max_iter,
+                });
+            }
+
+            // look for two consecutive small sub-diagonal elements
+            let mut m = nu - 2;
+            loop {
+                z = h[m][m];
+                r = x - z;
+                s = y - z;
+                p = (r * s - w) / h[m + 1][m] + h[m][m + 1];
+                q = h[m + 1][m + 1] - z - r - s;
+                r = h[m + 2][m + 1];
+                s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                if h[m][m - 1].abs() * (q.abs() + r.abs())
+                    < eps * (p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs()))
+                {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in (m + 2)..=nu {
+                h[i][i - 2] = T::zero();
+                if i > m + 2 {
+                    h[i][i - 3] = T::zero();
+                }
+            }
+
+            // double QR step involving rows l..=n and columns m..=n
+            for k in m..nu {
+                let notlast = k != nu - 1;
+                if k != m {
+                    p = h[k][k - 1];
+                    q = h[k + 1][k - 1];
+                    r = if notlast { h[k + 2][k - 1] } else { T::zero() };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == T::zero() {
+                        continue;
+                    }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+
+                s = (p * p + q * q + r * r).sqrt();
+                if p < T::zero() {
+                    s = -s;
+                }
+                if s == T::zero() {
+                    continue;
+                }
+                if k != m {
+                    h[k][k - 1] = -s * x;
+                } else if l != m {
+                    h[k][k - 1] = -h[k][k - 1];
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q /= p;
+                r /= p;
+
+                for j in k..nn {
+                    p = h[k][j] + q * h[k + 1][j];
+                    if notlast {
+                        p += r * h[k + 2][j];
+                        h[k + 2][j] -= p * z;
+                    }
+                    h[k][j] -= p * x;
+                    h[k + 1][j] -= p * y;
+                }
+                for i in 0..=nu.min(k + 3) {
+                    p = x * h[i][k] + y * h[i][k + 1];
+                    if notlast {
+                        p += z * h[i][k + 2];
+                        h[i][k + 2] -= p * r;
+                    }
+                    h[i][k] -= p;
+                    h[i][k + 1] -= p * q;
+                }
+                if want_vectors {
+                    for row in v.iter_mut() {
+                        p = x * row[k] + y * row[k + 1];
+                        if notlast {
+                            p += z * row[k + 2];
+                            row[k + 2] -= p * r;
+                        }
+                        row[k] -= p;
+                        row[k + 1] -= p * q;
+                    }
+                }
+            }
+        }
+    }
+
+    if want_vectors && norm != T::zero() {
+        back_substitute(h, v, d, e, norm);
+    }
+    Ok(())
+}
+
+// solves the quasi-triangular Schur form for its eigenvectors and maps them
+// back through the accumulated similarity transforms
+#[allow(clippy::needless_range_loop)]
+fn back_substitute<T: RealField>(h: &mut [Vec<T>], v: &mut [Vec<T>], d: &[T], e: &[T], norm: T) {
+    let nn = h.len();
+    let eps = T::EPSILON;
+    let (mut r, mut s, mut z) = (T::zero(), T::zero(), T::zero());
+
+    for n in (0..nn).rev() {
+        let p = d[n];
+        let q = e[n];
+
+        if q == T::zero() {
+            // real vector
+            let mut l = n;
+            h[n][n] = T::one();
+            for i in (0..n).rev() {
+                let w = h[i][i] - p;
+                r = (l..=n).map(|j| h[i][j] * h[j][n]).sum();
+                if e[i] < T::zero() {
+                    z = w;
+                    s = r;
+                    continue;
+                }
+                l = i;
+                if e[i] == T::zero() {
+                    h[i][n] = if w != T::zero() {
+                        -r / w
+                    } else {
+                        -r / (eps * norm)
+                    };
+                } else {
+                    // solve the real 2x2 system
+                    let x = h[i][i + 1];
+                    let y = h[i + 1][i];
+                    let denom = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                    let t = (x * s - z * r) / denom;
+                    h[i][n] = t;
+                    h[i + 1][n] = if x.abs() > z.abs() {
+                        (-r - w * t) / x
+                    } else {
+                        (-s - y * t) / z
+                    };
+                }
+
+                // overflow control
+                let t = h[i][n].abs();
+                if (eps * t) * t > T::one() {
+                    for j in i..=n {
+                        h[j][n] /= t;
+                    }
+                }
+            }
+        } else if q < T::zero() {
+            // complex vector, stored in columns n - 1 (real) and n (imaginary)
+            let mut l = n - 1;
+            if h[n][n - 1].abs() > h[n - 1][n].abs() {
+                h[n - 1][n - 1] = q / h[n][n - 1];
+                h[n - 1][n] = -(h[n][n] - p) / h[n][n - 1];
+            } else {
+                let c =
+                    Complex::new(T::zero(), -h[n - 1][n]) / Complex::new(h[n - 1][n - 1] - p, q);
+                h[n - 1][n - 1] = c.re;
+                h[n - 1][n] = c.im;
+            }
+            h[n][n - 1] = T::zero();
+            h[n][n] = T::one();
+
+            let (mut ra, mut sa);
+            for i in (0..n.saturating_sub(1)).rev() {
+                ra = T::zero();
+                sa = T::zero();
+                for j in l..=n {
+                    ra += h[i][j] * h[j][n - 1];
+                    sa += h[i][j] * h[j][n];
+                }
+                let w = h[i][i] - p;
+
+                if e[i] < T::zero() {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+                l = i;
+                if e[i] == T::zero() {
+                    let c = Complex::new(-ra, -sa) / Complex::new(w, q);
+                    h[i][n - 1] = c.re;
+                    h[i][n] = c.im;
+                } else {
+                    // solve the complex 2x2 system
+                    let x = h[i][i + 1];
+                    let y = h[i + 1][i];
+                    let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                    let vi = (d[i] - p) * T::from_f64(2.0) * q;
+                    if vr == T::zero() && vi == T::zero() {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+                    let c = Complex::new(x * r - z * ra + q * sa, x * s - z * sa - q * ra)
+                        / Complex::new(vr, vi);
+                    h[i][n - 1] = c.re;
+                    h[i][n] = c.im;
+                    if x.abs() > z.abs() + q.abs() {
+                        h[i + 1][n - 1] = (-ra - w * h[i][n - 1] + q * h[i][n]) / x;
+                        h[i + 1][n] = (-sa - w * h[i][n] - q * h[i][n - 1]) / x;
+                    } else {
+                        let c = Complex::new(-r - y * h[i][n - 1], -s - y * h[i][n])
+                            / Complex::new(z, q);
+                        h[i + 1][n - 1] = c.re;
+                        h[i + 1][n] = c.im;
+                    }
+                }
+
+                // overflow control
+                let t = h[i][n - 1].abs().max(h[i][n].abs());
+                if (eps * t) * t > T::one() {
+                    for j in i..=n {
+                        h[j][n - 1] /= t;
+                        h[j][n] /= t;
+                    }
+                }
+            }
+        }
+    }
+
+    // back transformation to eigenvectors of the original matrix
+    for j in (0..nn).rev() {
+        for i in 0..nn {
+            z = (0..=j).map(|k| v[i][k] * h[k][j]).sum();
+            v[i][j] = z;
+        }
+    }
+}
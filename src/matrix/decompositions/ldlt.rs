@@ -0,0 +1,103 @@
+use crate::matrix::decompositions::cholesky::swap_symmetric;
+use crate::matrix::decompositions::symmetric::check_symmetric;
+use crate::matrix::matrix::Matrix;
+use crate::utils::error::Error;
+use crate::utils::scalar::RealField;
+
+// LDL^T with Bunch-Kaufman pivoting for symmetric indefinite A:
+// P^T * A * P = L * D * L^T, with L unit lower triangular, D block diagonal
+// with 1x1 and 2x2 blocks, and (P^T A P)[i][j] = A[perm[i]][perm[j]]
+#[allow(clippy::type_complexity)]
+pub fn ldlt<T: RealField>(matrix: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>, Vec<usize>), Error> {
+    let n = check_symmetric(matrix, "ldlt")?;
+    let alpha = (T::one() + T::from_f64(17.0).sqrt()) / T::from_f64(8.0);
+
+    let mut a = matrix.to_row_major().into_owned();
+    let mut lower = Matrix::identity(n);
+    let mut block = Matrix::zeros(n, n);
+    let mut perm: Vec<usize> = (0..n).collect();
+
+    let mut k = 0;
+    while k < n {
+        let absakk = a.get(k, k).abs();
+        let (mut imax, mut colmax) = (k, T::zero());
+        for i in (k + 1)..n {
+            if a.get(i, k).abs() > colmax {
+                colmax = a.get(i, k).abs();
+                imax = i;
+            }
+        }
+
+        if absakk.max(colmax) == T::zero() {
+            // column is already zero, D gets a zero pivot
+            k += 1;
+            continue;
+        }
+
+        let (pivot, step) = if absakk >= alpha * colmax {
+            (k, 1)
+        } else {
+            let rowmax = (k..n)
+                .filter(|&j| j != imax)
+                .map(|j| a.get(imax, j).abs())
+                .fold(T::zero(), T::max);
+            if absakk >= alpha * colmax * (colmax / rowmax) {
+                (k, 1)
+            } else if a.get(imax, imax).abs() >= alpha * rowmax {
+                (imax, 1)
+            } else {
+                (imax, 2)
+            }
+        };
+
+        let target = k + step - 1;
+        if pivot != target {
+            swap_symmetric(&mut a, target, pivot);
+            for j in 0..k {
+                lower.data.swap(target * n + j, pivot * n + j);
+            }
+            perm.swap(target, pivot);
+        }
+
+        if step == 1 {
+            let d = a.get(k, k);
+            block.set(k, k, d);
+            for i in (k + 1)..n {
+                lower.set(i, k, a.get(i, k) / d);
+            }
+            for i in (k + 1)..n {
+                for j in (k + 1)..=i {
+                    let value = a.get(i, j) - a.get(i, k) * a.get(j, k) / d;
+                    a.set(i, j, value);
+                    a.set(j, i, value);
+                }
+            }
+        } else {
+            let (d11, d21, d22) = (a.get(k, k), a.get(k + 1, k), a.get(k + 1, k + 1));
+            block.set(k, k, d11);
+            block.set(k + 1, k, d21);
+            block.set(k, k + 1, d21);
+            block.set(k + 1, k + 1, d22);
+
+            let det = d11 * d22 - d21 * d21;
+            let (e11, e21, e22) = (d22 / det, -d21 / det, d11 / det);
+            for i in (k + 2)..n {
+                let (x, y) = (a.get(i, k), a.get(i, k + 1));
+                lower.set(i, k, x * e11 + y * e21);
+                lower.set(i, k + 1, x * e21 + y * e22);
+            }
+            for i in (k + 2)..n {
+                for j in (k + 2)..=i {
+                    let value = a.get(i, j)
+                        - lower.get(i, k) * a.get(j, k)
+                        - lower.get(i, k + 1) * a.get(j, k + 1);
+                    a.set(i, j, value);
+                    a.set(j, i, value);
+                }
+            }
+        }
+        k += step;
+    }
+
+    Ok((lower, block, perm))
+}
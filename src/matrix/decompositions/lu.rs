@@ -0,0 +1,287 @@
+use crate::matrix::decompositions::condition::{inverse_norm1_estimate, rcond_from};
+use crate::matrix::decompositions::triangular::{
+    block, set_block, set_column, solve_lower, solve_lower_transpose, solve_upper,
+    solve_upper_transpose, BLOCK_SIZE,
+};
+use crate::matrix::gemm::{gemm, Transpose};
+use crate::matrix::matrix::Matrix;
+use crate::matrix::norms::norm_1;
+use crate::utils::error::Error;
+use crate::utils::parallel;
+use crate::utils::scalar::RealField;
+
+// LU with partial (row) pivoting: P * A = L * U, where row i of P * A is row
+// perm[i] of A and sign is the determinant of P. Singular input still factors,
+// leaving zeros on the diagonal of U.
+#[allow(clippy::type_complexity)]
+pub fn lu<T: RealField>(
+    matrix: &Matrix<T>,
+) -> Result<(Matrix<T>, Matrix<T>, Vec<usize>, T), Error> {
+    let (packed, rows, _, sign) = lu_packed(matrix, false)?;
+    let (lower, upper) = unpack(&packed);
+    Ok((lower, upper, rows, sign))
+}
+
+// LU with full (row and column) pivoting: P * A * Q = L * U, where column j of
+// A * Q is column col_perm[j] of A; more robust for nearly rank-deficient input
+#[allow(clippy::type_complexity)]
+pub fn lu_full<T: RealField>(
+    matrix: &Matrix<T>,
+) -> Result<(Matrix<T>, Matrix<T>, Vec<usize>, Vec<usize>, T), Error> {
+    let (packed, rows, cols, sign) = lu_packed(matrix, true)?;
+    let (lower, upper) = unpack(&packed);
+    Ok((lower, upper, rows, cols, sign))
+}
+
+// in-place elimination: U on and above the diagonal, the multipliers of L
+// (with implicit unit diagonal) below it
+#[allow(clippy::type_complexity)]
+pub(crate) fn lu_packed<T: RealField>(
+    matrix: &Matrix<T>,
+    full_pivot: bool,
+) -> Result<(Matrix<T>, Vec<usize>, Vec<usize>, T), Error> {
+    if matrix.rows != matrix.cols {
+        return Err(Error::MatrixNotSquare {
+            op: "lu",
+            shape: matrix.shape(),
+        });
+    }
+
+    let n = matrix.rows;
+    let mut a = matrix.to_row_major().into_owned();
+    let mut rows: Vec<usize> = (0..n).collect();
+    let mut cols: Vec<usize> = (0..n).collect();
+    let mut sign = T::one();
+
+    // full pivoting searches the whole trailing matrix at every step, so only
+    // partial pivoting can defer the trailing update to a blocked GEMM
+    let panel = if full_pivot { n.max(1) } else { BLOCK_SIZE };
+    for k0 in (0..n).step_by(panel) {
+        let k1 = (k0 + panel).min(n);
+        for k in k0..k1 {
+            let (mut pivot_row, mut pivot_col) = (k, k);
+            let mut best = a.get(k, k).abs();
+            let col_range = if full_pivot { k..n } else { k..k + 1 };
+            for j in col_range {
+                for i in k..n {
+                    let candidate = a.get(i, j).abs();
+                    if candidate > best {
+                        best = candidate;
+                        pivot_row = i;
+                        pivot_col = j;
+                    }
+                }
+            }
+
+            if pivot_row != k {
+                for j in 0..n {
+                    a.data.swap(k * n + j, pivot_row * n + j);
+                }
+                rows.swap(k, pivot_row);
+                sign = -sign;
+            }
+            if pivot_col != k {
+                for i in 0..n {
+                    a.data.swap(i * n + k, i * n + pivot_col);
+                }
+                cols.swap(k, pivot_col);
+                sign = -sign;
+            }
+
+            let pivot = a.get(k, k);
+            if pivot == T::zero() {
+                // nothing to eliminate in this column
+                continue;
+            }
+            // rows below the pivot are independent, update them in parallel
+            let (above, below) = a.data.split_at_mut((k + 1) * n);
+            let pivot_row = &above[k * n..];
+            parallel::for_each_rows(below, n, (n - k) * (k1 - k), |_, run| {
+                for row in run.chunks_mut(n) {
+                    let factor = row[k] / pivot;
+                    row[k] = factor;
+                    if factor == T::zero() {
+                        continue;
+                    }
+                    for j in (k + 1)..k1 {
+                        row[j] -= factor * pivot_row[j];
+                    }
+                }
+            });
+        }
+        if k1 < n {
+            update_trailing(&mut a, k0, k1);
+        }
+    }
+
+    Ok((a, rows, cols, sign))
+}
+
+// after factoring the panel of columns k0..k1: U12 = L11^-1 * A12 and
+// A22 -= L21 * U12, the latter as one GEMM
+fn update_trailing<T: RealField>(a: &mut Matrix<T>, k0: usize, k1: usize) {
+    let n = a.rows;
+    for i in (k0 + 1)..k1 {
+        let (above, rest) = a.data.split_at_mut(i * n);
+        let row = &mut rest[..n];
+        for p in k0..i {
+            let factor = row[p];
+            for j in k1..n {
+                row[j] -= factor * above[p * n + j];
+            }
+        }
+    }
+
+    let mut a22 = block(a, k1..n, k1..n);
+    gemm(
+        -T::one(),
+        &a.slice(k1..n, k0..k1),
+        Transpose::No,
+        &a.slice(k0..k1, k1..n),
+        Transpose::No,
+        T::one(),
+        &mut a22,
+    )
+    .expect("block shapes agree");
+    set_block(a, k1, k1, &a22);
+}
+
+fn unpack<T: RealField>(packed: &Matrix<T>) -> (Matrix<T>, Matrix<T>) {
+    let n = packed.rows;
+    let mut lower = Matrix::identity(n);
+    let mut upper = Matrix::zeros(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            if j < i {
+                lower.set(i, j, packed.get(i, j));
+            } else {
+                upper.set(i, j, packed.get(i, j));
+            }
+        }
+    }
+    (lower, upper)
+}
+
+// reusable PLU factorization: factor once, then solve, invert or take the
+// determinant as often as needed
+#[derive(Debug, Clone)]
+pub struct LuFactor<T = f64> {
+    packed: Matrix<T>,
+    perm: Vec<usize>,
+    sign: T,
+    anorm: T,
+}
+
+impl<T: RealField> LuFactor<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<Self, Error> {
+        let (packed, perm, _, sign) = lu_packed(matrix, false)?;
+        Ok(Self {
+            packed,
+            perm,
+            sign,
+            anorm: norm_1(matrix),
+        })
+    }
+
+    pub fn lower(&self) -> Matrix<T> {
+        unpack(&self.packed).0
+    }
+
+    pub fn upper(&self) -> Matrix<T> {
+        unpack(&self.packed).1
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    // determinant of the row permutation, +1 or -1
+    pub fn permutation_sign(&self) -> T {
+        self.sign
+    }
+
+    // diagonal of U
+    pub fn pivots(&self) -> Vec<T> {
+        (0..self.packed.rows)
+            .map(|i| self.packed.get(i, i))
+            .collect()
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.zero_pivot().is_some()
+    }
+
+    // first column with an exactly zero pivot
+    pub fn zero_pivot(&self) -> Option<usize> {
+        (0..self.packed.rows).find(|&i| self.packed.get(i, i) == T::zero())
+    }
+
+    // solves A * X = B for every column of B
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        let n = self.packed.rows;
+        if b.rows != n {
+            return Err(Error::DimensionMismatch {
+                op: "lu solve",
+                left: self.packed.shape(),
+                right: b.shape(),
+            });
+        }
+        if let Some(pivot) = self.zero_pivot() {
+            return Err(Error::SingularMatrix { pivot });
+        }
+
+        let mut x = Matrix::zeros(n, b.cols);
+        for j in 0..b.cols {
+            let mut col: Vec<T> = self.perm.iter().map(|&p| b.get(p, j)).collect();
+            self.solve_in_place(&mut col);
+            set_column(&mut x, j, &col);
+        }
+        Ok(x)
+    }
+
+    pub fn determinant(&self) -> T {
+        self.pivots().iter().fold(self.sign, |det, &d| det * d)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<T>, Error> {
+        self.solve(&Matrix::identity(self.packed.rows))
+    }
+
+    // estimate of 1 / (||A||_1 * ||A^-1||_1); 0 for singular matrices
+    pub fn rcond(&self) -> T {
+        if self.is_singular() {
+            return T::zero();
+        }
+        let estimate = inverse_norm1_estimate(
+            self.packed.rows,
+            |x| {
+                let mut permuted: Vec<T> = self.perm.iter().map(|&p| x[p]).collect();
+                self.solve_in_place(&mut permuted);
+                x.copy_from_slice(&permuted);
+            },
+            |x| {
+                // A^T = U^T L^T P
+                solve_upper_transpose(&self.packed, x, false);
+                solve_lower_transpose(&self.packed, x, true);
+                let mut unpermuted = vec![T::zero(); x.len()];
+                for (i, &p) in self.perm.iter().enumerate() {
+                    unpermuted[p] = x[i];
+                }
+                x.copy_from_slice(&unpermuted);
+            },
+        );
+        rcond_from(self.anorm, estimate)
+    }
+
+    // estimate of the 1-norm condition number ||A||_1 * ||A^-1||_1, infinite
+    // for singular matrices
+    pub fn cond1_estimate(&self) -> T {
+        T::one() / self.rcond()
+    }
+
+    // L * U * x = b for an already permuted right-hand side
+    fn solve_in_place(&self, b: &mut [T]) {
+        solve_lower(&self.packed, b, true);
+        solve_upper(&self.packed, b, false);
+    }
+}
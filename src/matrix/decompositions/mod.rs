@@ -0,0 +1,19 @@
+mod cholesky;
+mod condition;
+mod eigen;
+mod ldlt;
+mod lu;
+mod qr;
+mod svd;
+mod symmetric;
+pub(crate) mod triangular;
+
+pub use cholesky::{cholesky, cholesky_pivoted, CholeskyFactor};
+pub use condition::inverse_norm1_estimate;
+pub use eigen::{eigen, eigenvalues};
+pub use ldlt::ldlt;
+pub use lu::{lu, lu_full, LuFactor};
+pub(crate) use qr::negligible_pivot;
+pub use qr::{qr, qr_full, qr_pivoted, QrFactor};
+pub use svd::{svd, svd_full};
+pub use symmetric::{eigen_symmetric, eigen_symmetric_top_k};
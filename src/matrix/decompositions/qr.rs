@@ -0,0 +1,357 @@
+use crate::matrix::decompositions::condition::{inverse_norm1_estimate, rcond_from};
+use crate::matrix::decompositions::triangular::{
+    block, column, set_block, set_column, solve_upper, solve_upper_transpose, BLOCK_SIZE,
+};
+use crate::matrix::gemm::{gemm, Transpose};
+use crate::matrix::matrix::Matrix;
+use crate::matrix::norms::norm_1;
+use crate::utils::error::Error;
+use crate::utils::scalar::RealField;
+
+// economy QR: A = Q * R with Q (m x k) orthonormal columns, R (k x n), k = min(m, n)
+pub fn qr<T: RealField>(matrix: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), Error> {
+    let (packed, tau, _) = householder(matrix, false);
+    let k = tau.len();
+    Ok((form_q(&packed, &tau, k), upper(&packed, k)))
+}
+
+// full QR: Q is m x m orthogonal and R is m x n
+pub fn qr_full<T: RealField>(matrix: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), Error> {
+    let (packed, tau, _) = householder(matrix, false);
+    Ok((
+        form_q(&packed, &tau, matrix.rows),
+        upper(&packed, matrix.rows),
+    ))
+}
+
+// column-pivoted QR: A[:, perm] = Q * R (economy sized), the diagonal of R is
+// non-increasing in magnitude so the numerical rank is read off directly
+#[allow(clippy::type_complexity)]
+pub fn qr_pivoted<T: RealField>(
+    matrix: &Matrix<T>,
+) -> Result<(Matrix<T>, Matrix<T>, Vec<usize>, usize), Error> {
+    let (packed, tau, perm) = householder(matrix, true);
+    let k = tau.len();
+    let tol = rank_tolerance(&packed, k, matrix.shape());
+    let rank = (0..k).take_while(|&i| packed.get(i, i).abs() > tol).count();
+
+    Ok((form_q(&packed, &tau, k), upper(&packed, k), perm, rank))
+}
+
+// diagonal entries of R at or below max(m, n) * eps * max |R[i][i]| are
+// rounding noise, for an m x n matrix whose R has k diagonal entries; with
+// column pivoting the largest is R[0][0], without it can sit anywhere
+fn rank_tolerance<T: RealField>(r: &Matrix<T>, k: usize, (m, n): (usize, usize)) -> T {
+    let largest = (0..k).fold(T::zero(), |acc, i| acc.max(r.get(i, i).abs()));
+    largest * T::EPSILON * T::from_usize(m.max(n))
+}
+
+// first numerically zero diagonal entry of R, see rank_tolerance
+pub(crate) fn negligible_pivot<T: RealField>(
+    r: &Matrix<T>,
+    k: usize,
+    shape: (usize, usize),
+) -> Option<usize> {
+    let tol = rank_tolerance(r, k, shape);
+    (0..k).find(|&i| r.get(i, i).abs() <= tol)
+}
+
+// in-place Householder factorization; R is left on and above the diagonal and
+// the reflector vectors (with implicit leading 1) below it
+#[allow(clippy::needless_range_loop)]
+fn householder<T: RealField>(matrix: &Matrix<T>, pivot: bool) -> (Matrix<T>, Vec<T>, Vec<usize>) {
+    let m = matrix.rows;
+    let n = matrix.cols;
+    let k = m.min(n);
+    let mut a = matrix.to_row_major().into_owned();
+    let mut tau = vec![T::zero(); k];
+    let mut perm: Vec<usize> = (0..n).collect();
+
+    // column pivoting needs every column up to date before each step, so it
+    // runs as a single panel; otherwise panels of BLOCK_SIZE reflectors are
+    // applied to the trailing columns at once
+    let panel = if pivot { k.max(1) } else { BLOCK_SIZE };
+    for k0 in (0..k).step_by(panel) {
+        let k1 = (k0 + panel).min(k);
+        let limit = if k1 == k { n } else { k1 };
+        for step in k0..k1 {
+            if pivot {
+                let norm_sq =
+                    |a: &Matrix<T>, j: usize| (step..m).map(|i| a.get(i, j).powi(2)).sum::<T>();
+                let mut best = step;
+                let mut best_norm = norm_sq(&a, step);
+                for j in (step + 1)..n {
+                    let candidate = norm_sq(&a, j);
+                    if candidate > best_norm {
+                        best = j;
+                        best_norm = candidate;
+                    }
+                }
+                if best != step {
+                    for i in 0..m {
+                        a.data.swap(i * n + step, i * n + best);
+                    }
+                    perm.swap(step, best);
+                }
+            }
+
+            let x0 = a.get(step, step);
+            let norm = (step..m).map(|i| a.get(i, step).powi(2)).sum::<T>().sqrt();
+            if norm == T::zero() {
+                continue;
+            }
+            let beta = if x0 >= T::zero() { -norm } else { norm };
+            let scale = T::one() / (x0 - beta);
+            for i in (step + 1)..m {
+                a.data[i * n + step] *= scale;
+            }
+            tau[step] = (beta - x0) / beta;
+            a.set(step, step, beta);
+
+            // apply H = I - tau * v * v^T to the rest of the panel
+            for j in (step + 1)..limit {
+                let mut w = a.get(step, j);
+                for i in (step + 1)..m {
+                    w += a.get(i, step) * a.get(i, j);
+                }
+                w *= tau[step];
+                a.data[step * n + j] -= w;
+                for i in (step + 1)..m {
+                    let update = w * a.get(i, step);
+                    a.data[i * n + j] -= update;
+                }
+            }
+        }
+        if limit < n {
+            apply_block(&mut a, &tau, k0, k1);
+        }
+    }
+
+    (a, tau, perm)
+}
+
+// applies H(k1 - 1) ... H(k0) to the columns right of the panel in compact WY
+// form, A2 <- (I - V * T^T * V^T) * A2, as three GEMMs
+fn apply_block<T: RealField>(a: &mut Matrix<T>, tau: &[T], k0: usize, k1: usize) {
+    let (m, n) = (a.rows, a.cols);
+    let nb = k1 - k0;
+
+    // unit lower trapezoidal reflectors of the panel
+    let mut v = block(a, k0..m, k0..k1);
+    for i in 0..nb {
+        v.set(i, i, T::one());
+        for j in (i + 1)..nb {
+            v.set(i, j, T::zero());
+        }
+    }
+
+    // upper triangular T with H(k0) ... H(k1 - 1) = I - V * T * V^T
+    let mut t = Matrix::zeros(nb, nb);
+    for i in 0..nb {
+        let tau_i = tau[k0 + i];
+        if tau_i == T::zero() {
+            continue;
+        }
+        let z: Vec<T> = (0..i)
+            .map(|j| (i..v.rows).map(|r| v.get(r, j) * v.get(r, i)).sum())
+            .collect();
+        for j in 0..i {
+            let value: T = (j..i).map(|l| t.get(j, l) * z[l]).sum();
+            t.set(j, i, -tau_i * value);
+        }
+        t.set(i, i, tau_i);
+    }
+
+    let mut a2 = block(a, k0..m, k1..n);
+    let mut w = Matrix::zeros(nb, n - k1);
+    let mut tw = Matrix::zeros(nb, n - k1);
+    gemm(
+        T::one(),
+        &v,
+        Transpose::Yes,
+        &a2,
+        Transpose::No,
+        T::zero(),
+        &mut w,
+    )
+    .and_then(|_| {
+        gemm(
+            T::one(),
+            &t,
+            Transpose::Yes,
+            &w,
+            Transpose::No,
+            T::zero(),
+            &mut tw,
+        )
+    })
+    .and_then(|_| {
+        gemm(
+            -T::one(),
+            &v,
+            Transpose::No,
+            &tw,
+            Transpose::No,
+            T::one(),
+            &mut a2,
+        )
+    })
+    .expect("block shapes agree");
+    set_block(a, k0, k1, &a2);
+}
+
+// accumulates the reflectors backwards into the first `cols` columns of Q
+fn form_q<T: RealField>(packed: &Matrix<T>, tau: &[T], cols: usize) -> Matrix<T> {
+    let m = packed.rows;
+    let mut q = Matrix::zeros(m, cols);
+    for i in 0..m.min(cols) {
+        q.set(i, i, T::one());
+    }
+
+    for step in (0..tau.len()).rev() {
+        if tau[step] == T::zero() {
+            continue;
+        }
+        for j in 0..cols {
+            let mut w = q.get(step, j);
+            for i in (step + 1)..m {
+                w += packed.get(i, step) * q.get(i, j);
+            }
+            w *= tau[step];
+            q.data[step * cols + j] -= w;
+            for i in (step + 1)..m {
+                q.data[i * cols + j] -= w * packed.get(i, step);
+            }
+        }
+    }
+    q
+}
+
+fn upper<T: RealField>(packed: &Matrix<T>, rows: usize) -> Matrix<T> {
+    let mut r = Matrix::zeros(rows, packed.cols);
+    for i in 0..rows.min(packed.rows) {
+        for j in i..packed.cols {
+            r.set(i, j, packed.get(i, j));
+        }
+    }
+    r
+}
+
+// reusable Householder QR; `solve` gives the least-squares solution of an
+// overdetermined (m >= n) full-rank system
+#[derive(Debug, Clone)]
+pub struct QrFactor<T = f64> {
+    packed: Matrix<T>,
+    tau: Vec<T>,
+}
+
+impl<T: RealField> QrFactor<T> {
+    pub fn new(matrix: &Matrix<T>) -> Result<Self, Error> {
+        let (packed, tau, _) = householder(matrix, false);
+        Ok(Self { packed, tau })
+    }
+
+    pub fn q(&self) -> Matrix<T> {
+        form_q(&self.packed, &self.tau, self.tau.len())
+    }
+
+    pub fn r(&self) -> Matrix<T> {
+        upper(&self.packed, self.tau.len())
+    }
+
+    // full column rank up to rounding, see rank_tolerance
+    pub fn is_full_rank(&self) -> bool {
+        self.packed.rows >= self.packed.cols && self.negligible_pivot().is_none()
+    }
+
+    fn negligible_pivot(&self) -> Option<usize> {
+        negligible_pivot(&self.packed, self.packed.cols, self.packed.shape())
+    }
+
+    // minimises ||A * X - B|| column by column
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        let (m, n) = (self.packed.rows, self.packed.cols);
+        if b.rows != m || m < n {
+            return Err(Error::DimensionMismatch {
+                op: "qr solve",
+                left: self.packed.shape(),
+                right: b.shape(),
+            });
+        }
+        if let Some(pivot) = self.negligible_pivot() {
+            return Err(Error::SingularMatrix { pivot });
+        }
+
+        let mut x = Matrix::zeros(n, b.cols);
+        for j in 0..b.cols {
+            let mut col = column(b, j);
+            self.apply_qt(&mut col);
+            col.truncate(n);
+            solve_upper(&self.packed, &mut col, false);
+            set_column(&mut x, j, &col);
+        }
+        Ok(x)
+    }
+
+    pub fn determinant(&self) -> Result<T, Error> {
+        if self.packed.rows != self.packed.cols {
+            return Err(Error::MatrixNotSquare {
+                op: "qr determinant",
+                shape: self.packed.shape(),
+            });
+        }
+        // every non-trivial reflector contributes a factor of -1
+        let reflections = self.tau.iter().filter(|&&t| t != T::zero()).count();
+        let sign = if reflections % 2 == 0 {
+            T::one()
+        } else {
+            -T::one()
+        };
+        Ok((0..self.packed.rows).fold(sign, |det, i| det * self.packed.get(i, i)))
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<T>, Error> {
+        if self.packed.rows != self.packed.cols {
+            return Err(Error::MatrixNotSquare {
+                op: "qr inverse",
+                shape: self.packed.shape(),
+            });
+        }
+        self.solve(&Matrix::identity(self.packed.rows))
+    }
+
+    // reciprocal 1-norm condition estimate of R. R shares A's 2-norm
+    // condition number but its 1-norm one can differ by up to a factor of n,
+    // so this is a proxy for A's conditioning rather than LuFactor::rcond
+    pub fn rcond(&self) -> T {
+        if !self.is_full_rank() {
+            return T::zero();
+        }
+        let n = self.packed.cols;
+        let r = upper(&self.packed, n);
+        let estimate = inverse_norm1_estimate(
+            n,
+            |x| solve_upper(&r, x, false),
+            |x| solve_upper_transpose(&r, x, false),
+        );
+        rcond_from(norm_1(&r), estimate)
+    }
+
+    // b <- Q^T * b
+    fn apply_qt(&self, b: &mut [T]) {
+        for (step, &tau) in self.tau.iter().enumerate() {
+            if tau == T::zero() {
+                continue;
+            }
+            let mut w = b[step];
+            for (i, &x) in b.iter().enumerate().skip(step + 1) {
+                w += self.packed.get(i, step) * x;
+            }
+            w *= tau;
+            b[step] -= w;
+            for (i, x) in b.iter_mut().enumerate().skip(step + 1) {
+                *x -= w * self.packed.get(i, step);
+            }
+        }
+    }
+}
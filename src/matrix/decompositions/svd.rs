@@ -0,0 +1,181 @@
+use crate::matrix::matrix::Matrix;
+use crate::matrix::ops::transpose;
+use crate::utils::error::Error;
+use crate::utils::scalar::RealField;
+
+const MAX_SWEEPS: usize = 60;
+
+// thin SVD: A = U * diag(s) * Vt with U (m x k), s (k), Vt (k x n), k = min(m, n)
+#[allow(clippy::type_complexity)]
+pub fn svd<T: RealField>(matrix: &Matrix<T>) -> Result<(Matrix<T>, Vec<T>, Matrix<T>), Error> {
+    svd_impl(matrix, false)
+}
+
+// full SVD: U is m x m and Vt is n x n, s still holds min(m, n) values
+#[allow(clippy::type_complexity)]
+pub fn svd_full<T: RealField>(matrix: &Matrix<T>) -> Result<(Matrix<T>, Vec<T>, Matrix<T>), Error> {
+    svd_impl(matrix, true)
+}
+
+#[allow(clippy::type_complexity)]
+fn svd_impl<T: RealField>(
+    matrix: &Matrix<T>,
+    full: bool,
+) -> Result<(Matrix<T>, Vec<T>, Matrix<T>), Error> {
+    if matrix.rows >= matrix.cols {
+        return jacobi_svd(matrix, full);
+    }
+
+    // wide matrix: decompose A^T = U' S V'^T, then A = V' S U'^T
+    let (u, s, vt) = jacobi_svd(&transpose(matrix), full)?;
+    Ok((transpose(&vt), s, transpose(&u)))
+}
+
+// one-sided (Hestenes) Jacobi for m >= n, working on columns stored contiguously
+#[allow(clippy::type_complexity)]
+fn jacobi_svd<T: RealField>(
+    matrix: &Matrix<T>,
+    full: bool,
+) -> Result<(Matrix<T>, Vec<T>, Matrix<T>), Error> {
+    let m = matrix.rows;
+    let n = matrix.cols;
+
+    // column-major copies so that column rotations touch contiguous memory
+    let mut u = vec![T::zero(); m * n];
+    for i in 0..m {
+        for j in 0..n {
+            u[j * m + i] = matrix.get(i, j);
+        }
+    }
+    let mut v = vec![T::zero(); n * n];
+    for j in 0..n {
+        v[j * n + j] = T::one();
+    }
+
+    let tol = T::EPSILON * T::from_usize(m).sqrt().max(T::one());
+    // columns that have collapsed to rounding noise are left alone, otherwise
+    // rank-deficient input keeps rotating noise against noise forever
+    let floor = u.iter().map(|&x| x * x).sum::<T>() * T::EPSILON * T::EPSILON;
+    let mut converged = n < 2;
+    for _ in 0..MAX_SWEEPS {
+        if converged {
+            break;
+        }
+        let mut rotated = false;
+        for p in 0..n - 1 {
+            for q in (p + 1)..n {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for i in 0..m {
+                    let up = u[p * m + i];
+                    let uq = u[q * m + i];
+                    alpha += up * up;
+                    beta += uq * uq;
+                    gamma += up * uq;
+                }
+                if alpha <= floor || beta <= floor || gamma.abs() <= tol * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (T::from_f64(2.0) * gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = T::one() / (T::one() + t * t).sqrt();
+                let s = c * t;
+
+                rotate_columns(&mut u, m, p, q, c, s);
+                rotate_columns(&mut v, n, p, q, c, s);
+            }
+        }
+        converged = !rotated;
+    }
+    if !converged {
+        return Err(Error::NotConverged {
+            op: "svd",
+            iters: MAX_SWEEPS,
+        });
+    }
+
+    // singular values are the column norms, sorted descending
+    let norms: Vec<T> = (0..n)
+        .map(|j| {
+            u[j * m..(j + 1) * m]
+                .iter()
+                .map(|&x| x * x)
+                .sum::<T>()
+                .sqrt()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| norms[b].total_cmp(&norms[a]));
+    let singular: Vec<T> = order.iter().map(|&j| norms[j]).collect();
+
+    // columns belonging to (numerically) zero singular values carry no direction
+    // and are rebuilt as an orthonormal completion
+    let cutoff = singular.first().copied().unwrap_or(T::zero()) * T::EPSILON * T::from_usize(m);
+    let u_cols = if full { m } else { n };
+    let mut basis: Vec<Vec<T>> = Vec::with_capacity(u_cols);
+    for &j in &order {
+        if norms[j] > cutoff && norms[j] > T::zero() {
+            basis.push(
+                u[j * m..(j + 1) * m]
+                    .iter()
+                    .map(|&x| x / norms[j])
+                    .collect(),
+            );
+        }
+    }
+    complete_basis(&mut basis, m, u_cols);
+
+    // zero singular values sort last, so the completed columns line up with them
+    let mut left = Matrix::zeros(m, u_cols);
+    for (k, col) in basis.iter().enumerate() {
+        for (i, &value) in col.iter().enumerate().take(m) {
+            left.set(i, k, value);
+        }
+    }
+
+    let mut right_t = Matrix::zeros(n, n);
+    for (k, &j) in order.iter().enumerate() {
+        for i in 0..n {
+            right_t.set(k, i, v[j * n + i]);
+        }
+    }
+
+    Ok((left, singular, right_t))
+}
+
+fn rotate_columns<T: RealField>(data: &mut [T], len: usize, p: usize, q: usize, c: T, s: T) {
+    for i in 0..len {
+        let xp = data[p * len + i];
+        let xq = data[q * len + i];
+        data[p * len + i] = c * xp - s * xq;
+        data[q * len + i] = s * xp + c * xq;
+    }
+}
+
+// extends an orthonormal set of vectors of length `dim` to `target` vectors
+// by Gram-Schmidt against the standard basis
+fn complete_basis<T: RealField>(basis: &mut Vec<Vec<T>>, dim: usize, target: usize) {
+    while basis.len() < target {
+        let mut best: Option<(T, Vec<T>)> = None;
+        for e in 0..dim {
+            let mut candidate = vec![T::zero(); dim];
+            candidate[e] = T::one();
+            // two passes keep the result orthogonal to working precision
+            for _ in 0..2 {
+                for b in basis.iter() {
+                    let proj: T = b.iter().zip(&candidate).map(|(&x, &y)| x * y).sum();
+                    for i in 0..dim {
+                        candidate[i] -= proj * b[i];
+                    }
+                }
+            }
+            let norm = candidate.iter().map(|&x| x * x).sum::<T>().sqrt();
+            if best.as_ref().is_none_or(|(n, _)| norm > *n) {
+                best = Some((norm, candidate));
+            }
+        }
+        let (norm, candidate) = best.expect("basis dimension exceeded");
+        basis.push(candidate.into_iter().map(|x| x / norm).collect());
+    }
+}
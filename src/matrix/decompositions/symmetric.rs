@@ -0,0 +1,369 @@
+use crate::matrix::matrix::Matrix;
+use crate::utils::error::Error;
+use crate::utils::scalar::RealField;
+
+const MAX_QL_ITER: usize = 60;
+const INVERSE_ITERATIONS: usize = 4;
+
+// eigen-decomposition of a symmetric matrix: real eigenvalues sorted descending
+// and the matching orthonormal eigenvectors as columns
+pub fn eigen_symmetric<T: RealField>(matrix: &Matrix<T>) -> Result<(Vec<T>, Matrix<T>), Error> {
+    let n = check_symmetric(matrix, "eigen_symmetric")?;
+    let (mut v, mut d, mut e) = tridiagonalize(matrix);
+    tql2(&mut d, &mut e, Some(&mut v))?;
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| d[b].total_cmp(&d[a]));
+
+    let values = order.iter().map(|&j| d[j]).collect();
+    let mut vectors = Matrix::zeros(n, n);
+    for (k, &j) in order.iter().enumerate() {
+        for (i, row) in v.iter().enumerate() {
+            vectors.set(i, k, row[j]);
+        }
+    }
+    Ok((values, vectors))
+}
+
+// only the k largest eigenpairs: eigenvalues come from a vector-free QL sweep
+// over the tridiagonal form and the vectors from inverse iteration, so the cost
+// beyond the reduction is O(n^2 k) rather than O(n^3)
+pub fn eigen_symmetric_top_k<T: RealField>(
+    matrix: &Matrix<T>,
+    k: usize,
+) -> Result<(Vec<T>, Matrix<T>), Error> {
+    let n = check_symmetric(matrix, "eigen_symmetric_top_k")?;
+    let k = k.min(n);
+    let (q, diag, off) = tridiagonalize(matrix);
+
+    let mut d = diag.clone();
+    let mut e = off.clone();
+    tql2(&mut d, &mut e, None)?;
+    d.sort_by(|a, b| b.total_cmp(a));
+    d.truncate(k);
+
+    // off[i] couples rows i - 1 and i of the tridiagonal matrix
+    let sub: Vec<T> = off.iter().skip(1).copied().collect();
+    let scale = diag
+        .iter()
+        .chain(&sub)
+        .fold(T::zero(), |acc, x| acc.max(x.abs()));
+
+    let mut found: Vec<Vec<T>> = Vec::with_capacity(k);
+    for &lambda in &d {
+        let mut x: Vec<T> = (0..n)
+            .map(|i| T::one() + T::from_usize((i * 7919) % 101) / T::from_f64(101.0))
+            .collect();
+        for iteration in 1..=INVERSE_ITERATIONS {
+            x = tridiagonal_solve(&diag, &sub, lambda, scale, &x);
+            // keep vectors of clustered eigenvalues apart
+            for prev in &found {
+                let proj: T = prev.iter().zip(&x).map(|(&a, &b)| a * b).sum();
+                for i in 0..n {
+                    x[i] -= proj * prev[i];
+                }
+            }
+            let norm = x.iter().map(|&v| v * v).sum::<T>().sqrt();
+            if norm == T::zero() {
+                return Err(Error::NotConverged {
+                    op: "eigen_symmetric_top_k",
+                    iters: iteration,
+                });
+            }
+            x.iter_mut().for_each(|v| *v /= norm);
+        }
+        found.push(x);
+    }
+
+    let mut vectors = Matrix::zeros(n, k);
+    for (col, z) in found.iter().enumerate() {
+        for (i, qi) in q.iter().enumerate() {
+            let value: T = qi.iter().zip(z).map(|(&q, &z)| q * z).sum();
+            vectors.set(i, col, value);
+        }
+    }
+    Ok((d, vectors))
+}
+
+pub(crate) fn check_symmetric<T: RealField>(
+    matrix: &Matrix<T>,
+    op: &'static str,
+) -> Result<usize, Error> {
+    if matrix.rows != matrix.cols {
+        return Err(Error::MatrixNotSquare {
+            op,
+            shape: matrix.shape(),
+        });
+    }
+    // loose enough for f32 products such as A * A^T
+    let tol = T::from_f64(1e-9).max(T::from_f64(16.0) * T::EPSILON);
+    for i in 0..matrix.rows {
+        for j in 0..i {
+            let a = matrix.get(i, j);
+            let b = matrix.get(j, i);
+            if (a - b).abs() > tol * (T::one() + a.abs().max(b.abs())) {
+                return Err(Error::MatrixNotSymmetric {
+                    row: i,
+                    col: j,
+                    tolerance: tol.to_f64(),
+                });
+            }
+        }
+    }
+    Ok(matrix.rows)
+}
+
+// Householder reduction to tridiagonal form (EISPACK tred2) using the lower
+// triangle; returns Q, the diagonal, and the sub-diagonal with off[0] = 0
+#[allow(clippy::needless_range_loop)]
+fn tridiagonalize<T: RealField>(matrix: &Matrix<T>) -> (Vec<Vec<T>>, Vec<T>, Vec<T>) {
+    let n = matrix.rows;
+    let mut v: Vec<Vec<T>> = (0..n).map(|i| matrix.row(i).iter().collect()).collect();
+    let mut d = vec![T::zero(); n];
+    let mut e = vec![T::zero(); n];
+    if n == 0 {
+        return (v, d, e);
+    }
+
+    d.copy_from_slice(&v[n - 1]);
+
+    for i in (1..n).rev() {
+        let scale: T = d[..i].iter().map(|x| x.abs()).sum();
+        let mut h = T::zero();
+
+        if scale == T::zero() {
+            e[i] = d[i - 1];
+            for j in 0..i {
+                d[j] = v[i - 1][j];
+                v[i][j] = T::zero();
+                v[j][i] = T::zero();
+            }
+        } else {
+            for k in 0..i {
+                d[k] /= scale;
+                h += d[k] * d[k];
+            }
+            let mut f = d[i - 1];
+            let mut g = h.sqrt();
+            if f > T::zero() {
+                g = -g;
+            }
+            e[i] = scale * g;
+            h -= f * g;
+            d[i - 1] = f - g;
+            for j in 0..i {
+                e[j] = T::zero();
+            }
+
+            for j in 0..i {
+                f = d[j];
+                v[j][i] = f;
+                g = e[j] + v[j][j] * f;
+                for k in (j + 1)..i {
+                    g += v[k][j] * d[k];
+                    e[k] += v[k][j] * f;
+                }
+                e[j] = g;
+            }
+            f = T::zero();
+            for j in 0..i {
+                e[j] /= h;
+                f += e[j] * d[j];
+            }
+            let hh = f / (h + h);
+            for j in 0..i {
+                e[j] -= hh * d[j];
+            }
+            for j in 0..i {
+                f = d[j];
+                g = e[j];
+                for k in j..i {
+                    v[k][j] -= f * e[k] + g * d[k];
+                }
+                d[j] = v[i - 1][j];
+                v[i][j] = T::zero();
+            }
+        }
+        d[i] = h;
+    }
+
+    // accumulate the transformations
+    for i in 0..n - 1 {
+        v[n - 1][i] = v[i][i];
+        v[i][i] = T::one();
+        let h = d[i + 1];
+        if h != T::zero() {
+            for k in 0..=i {
+                d[k] = v[k][i + 1] / h;
+            }
+            for j in 0..=i {
+                let g: T = (0..=i).map(|k| v[k][i + 1] * v[k][j]).sum();
+                for k in 0..=i {
+                    v[k][j] -= g * d[k];
+                }
+            }
+        }
+        for k in 0..=i {
+            v[k][i + 1] = T::zero();
+        }
+    }
+    for j in 0..n {
+        d[j] = v[n - 1][j];
+        v[n - 1][j] = T::zero();
+    }
+    v[n - 1][n - 1] = T::one();
+    e[0] = T::zero();
+
+    (v, d, e)
+}
+
+// implicit QL iteration on the tridiagonal matrix (EISPACK tql2); the
+// eigenvectors are accumulated into `v` when given
+fn tql2<T: RealField>(d: &mut [T], e: &mut [T], mut v: Option<&mut [Vec<T>]>) -> Result<(), Error> {
+    let n = d.len();
+    if n == 0 {
+        return Ok(());
+    }
+    for i in 1..n {
+        e[i - 1] = e[i];
+    }
+    e[n - 1] = T::zero();
+
+    let eps = T::EPSILON;
+    let mut f = T::zero();
+    let mut tst1 = T::zero();
+    for l in 0..n {
+        tst1 = tst1.max(d[l].abs() + e[l].abs());
+        let mut m = l;
+        while m < n - 1 && e[m].abs() > eps * tst1 {
+            m += 1;
+        }
+
+        if m > l {
+            let mut iter = 0;
+            loop {
+                iter += 1;
+                if iter > MAX_QL_ITER {
+                    return Err(Error::NotConverged {
+                        op: "symmetric QL",
+                        iters: MAX_QL_ITER,
+                    });
+                }
+
+                // implicit shift
+                let mut g = d[l];
+                let mut p = (d[l + 1] - g) / (T::from_f64(2.0) * e[l]);
+                let mut r = p.hypot(T::one());
+                if p < T::zero() {
+                    r = -r;
+                }
+                d[l] = e[l] / (p + r);
+                d[l + 1] = e[l] * (p + r);
+                let dl1 = d[l + 1];
+                let mut h = g - d[l];
+                for di in d.iter_mut().skip(l + 2) {
+                    *di -= h;
+                }
+                f += h;
+
+                // implicit QL transformation
+                p = d[m];
+                let (mut c, mut c2, mut c3) = (T::one(), T::one(), T::one());
+                let el1 = e[l + 1];
+                let (mut s, mut s2) = (T::zero(), T::zero());
+                for i in (l..m).rev() {
+                    c3 = c2;
+                    c2 = c;
+                    s2 = s;
+                    g = c * e[i];
+                    h = c * p;
+                    r = p.hypot(e[i]);
+                    e[i + 1] = s * r;
+                    s = e[i] / r;
+                    c = p / r;
+                    p = c * d[i] - s * g;
+                    d[i + 1] = h + s * (c * g + s * d[i]);
+
+                    if let Some(v) = v.as_deref_mut() {
+                        for row in v.iter_mut() {
+                            h = row[i + 1];
+                            row[i + 1] = s * row[i] + c * h;
+                            row[i] = c * row[i] - s * h;
+                        }
+                    }
+                }
+                p = -s * s2 * c3 * el1 * e[l] / dl1;
+                e[l] = s * p;
+                d[l] = c * p;
+
+                if e[l].abs() <= eps * tst1 {
+                    break;
+                }
+            }
+        }
+        d[l] += f;
+        e[l] = T::zero();
+    }
+    Ok(())
+}
+
+// solves (T - shift * I) x = b for symmetric tridiagonal T with partial
+// pivoting; exactly singular pivots are nudged so inverse iteration can proceed
+fn tridiagonal_solve<T: RealField>(diag: &[T], sub: &[T], shift: T, scale: T, b: &[T]) -> Vec<T> {
+    let n = diag.len();
+    let tiny = T::EPSILON * scale.max(T::MIN_POSITIVE);
+    let mut d: Vec<T> = diag.iter().map(|&x| x - shift).collect();
+    let mut dl = sub.to_vec();
+    let mut du = sub.to_vec();
+    let mut du2 = vec![T::zero(); n.saturating_sub(2)];
+    let mut swapped = vec![false; n.saturating_sub(1)];
+
+    for i in 0..n.saturating_sub(1) {
+        if d[i].abs() >= dl[i].abs() {
+            if d[i] == T::zero() {
+                d[i] = tiny;
+            }
+            let fact = dl[i] / d[i];
+            dl[i] = fact;
+            d[i + 1] -= fact * du[i];
+        } else {
+            let fact = d[i] / dl[i];
+            d[i] = dl[i];
+            dl[i] = fact;
+            let temp = du[i];
+            du[i] = d[i + 1];
+            d[i + 1] = temp - fact * d[i + 1];
+            if i + 2 < n {
+                du2[i] = du[i + 1];
+                du[i + 1] *= -fact;
+            }
+            swapped[i] = true;
+        }
+    }
+    if n > 0 && d[n - 1] == T::zero() {
+        d[n - 1] = tiny;
+    }
+
+    let mut x = b.to_vec();
+    for i in 0..n.saturating_sub(1) {
+        if swapped[i] {
+            let temp = x[i];
+            x[i] = x[i + 1];
+            x[i + 1] = temp - dl[i] * x[i];
+        } else {
+            let update = dl[i] * x[i];
+            x[i + 1] -= update;
+        }
+    }
+    for i in (0..n).rev() {
+        let mut value = x[i];
+        if i + 1 < n {
+            value -= du[i] * x[i + 1];
+        }
+        if i + 2 < n {
+            value -= du2[i] * x[i + 2];
+        }
+        x[i] = value / d[i];
+    }
+    x
+}
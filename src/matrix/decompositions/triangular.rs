@@ -0,0 +1,111 @@
+use std::ops::Range;
+
+use crate::matrix::matrix::Matrix;
+use crate::utils::scalar::RealField;
+
+// panel width of the blocked LU and QR factorizations
+pub(crate) const BLOCK_SIZE: usize = 64;
+
+// in-place triangular solves against the lower or upper triangle of a square
+// (possibly packed) matrix; `unit` treats the diagonal as all ones
+
+pub(crate) fn solve_lower<T: RealField>(a: &Matrix<T>, b: &mut [T], unit: bool) {
+    let n = b.len();
+    for i in 0..n {
+        let mut value = b[i];
+        for (j, &bj) in b[..i].iter().enumerate() {
+            value -= a.data[i * a.cols + j] * bj;
+        }
+        b[i] = if unit {
+            value
+        } else {
+            value / a.data[i * a.cols + i]
+        };
+    }
+}
+
+pub(crate) fn solve_upper<T: RealField>(a: &Matrix<T>, b: &mut [T], unit: bool) {
+    let n = b.len();
+    for i in (0..n).rev() {
+        let mut value = b[i];
+        for (j, &bj) in b.iter().enumerate().skip(i + 1) {
+            value -= a.data[i * a.cols + j] * bj;
+        }
+        b[i] = if unit {
+            value
+        } else {
+            value / a.data[i * a.cols + i]
+        };
+    }
+}
+
+// solves L^T x = b using the lower triangle
+pub(crate) fn solve_lower_transpose<T: RealField>(a: &Matrix<T>, b: &mut [T], unit: bool) {
+    let n = b.len();
+    for i in (0..n).rev() {
+        let mut value = b[i];
+        for (j, &bj) in b.iter().enumerate().skip(i + 1) {
+            value -= a.data[j * a.cols + i] * bj;
+        }
+        b[i] = if unit {
+            value
+        } else {
+            value / a.data[i * a.cols + i]
+        };
+    }
+}
+
+// solves U^T x = b using the upper triangle
+pub(crate) fn solve_upper_transpose<T: RealField>(a: &Matrix<T>, b: &mut [T], unit: bool) {
+    let n = b.len();
+    for i in 0..n {
+        let mut value = b[i];
+        for (j, &bj) in b[..i].iter().enumerate() {
+            value -= a.data[j * a.cols + i] * bj;
+        }
+        b[i] = if unit {
+            value
+        } else {
+            value / a.data[i * a.cols + i]
+        };
+    }
+}
+
+pub(crate) fn column<T: RealField>(matrix: &Matrix<T>, j: usize) -> Vec<T> {
+    (0..matrix.rows).map(|i| matrix.get(i, j)).collect()
+}
+
+pub(crate) fn set_column<T: RealField>(matrix: &mut Matrix<T>, j: usize, values: &[T]) {
+    for (i, value) in values.iter().enumerate() {
+        matrix.set(i, j, *value);
+    }
+}
+
+// copies rows x cols out of a matrix
+pub(crate) fn block<T: RealField>(
+    matrix: &Matrix<T>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> Matrix<T> {
+    let mut data = Vec::with_capacity(rows.len() * cols.len());
+    for i in rows.clone() {
+        data.extend_from_slice(
+            &matrix.data[i * matrix.cols + cols.start..i * matrix.cols + cols.end],
+        );
+    }
+    Matrix::new(rows.len(), cols.len(), data)
+}
+
+// writes `values` into the matrix with its top-left corner at (row, col)
+pub(crate) fn set_block<T: RealField>(
+    matrix: &mut Matrix<T>,
+    row: usize,
+    col: usize,
+    values: &Matrix<T>,
+) {
+    for i in 0..values.rows {
+        let start = (row + i) * matrix.cols + col;
+        matrix.data[start..start + values.cols]
+            .copy_from_slice(&values.data[i * values.cols..(i + 1) * values.cols]);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::matrix::matrix::Matrix;
+use crate::matrix::ops;
+use crate::matrix::view::AsView;
+use crate::utils::error::Error;
+use crate::utils::parallel;
+use crate::utils::scalar::{RealField, Scalar};
+
+// Element-wise maps over a Matrix and the usual math and activation functions
+// built on them. Like the element-wise ops they run across threads with the
+// `parallel` feature, so the closures must be Sync, and the results keep the
+// layout of the input.
+
+impl<T: Scalar> Matrix<T> {
+    // f applied to every element
+    pub fn map<U: Scalar>(&self, f: impl Fn(T) -> U + Sync) -> Matrix<U> {
+        ops::map_elements(self, f)
+    }
+
+    pub fn map_inplace(&mut self, f: impl Fn(T) -> T + Sync) {
+        let work = self.data.len();
+        parallel::for_each_rows(&mut self.data, 1, work, |_, run| {
+            for x in run {
+                *x = f(*x);
+            }
+        });
+    }
+
+    // f(a, b) on matching elements, broadcasting `other` like ops::add
+    pub fn zip_map<U: Scalar>(
+        &self,
+        other: &impl AsView<T>,
+        f: impl Fn(T, T) -> U + Sync,
+    ) -> Result<Matrix<U>, Error> {
+        ops::broadcast_with("zip_map", self, other, f)
+    }
+
+    // in place, giving f the (row, col) of each element, e.g. for masks that
+    // depend on position; runs on the calling thread so f may be FnMut
+    pub fn apply(&mut self, mut f: impl FnMut(usize, usize, T) -> T) {
+        for k in 0..self.data.len() {
+            let (i, j) = self.position(k);
+            self.data[k] = f(i, j, self.data[k]);
+        }
+    }
+}
+
+pub fn exp<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    ops::map_elements(a, T::exp)
+}
+
+pub fn ln<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    ops::map_elements(a, T::ln)
+}
+
+pub fn sqrt<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    ops::map_elements(a, T::sqrt)
+}
+
+pub fn abs<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    ops::map_elements(a, |x| x.abs())
+}
+
+// every element limited to [low, high]; NaN stays NaN, and infinite bounds
+// leave that side open
+pub fn clamp<T: RealField>(a: &impl AsView<T>, low: T, high: T) -> Result<Matrix<T>, Error> {
+    if low.is_nan() || high.is_nan() {
+        return Err(Error::InvalidArgument {
+            op: "clamp",
+            reason: "low and high must not be NaN",
+        });
+    }
+    if low > high {
+        return Err(Error::InvalidArgument {
+            op: "clamp",
+            reason: "low must not exceed high",
+        });
+    }
+    Ok(ops::map_elements(a, |x| {
+        if x < low {
+            low
+        } else if x > high {
+            high
+        } else {
+            x
+        }
+    }))
+}
+
+pub fn tanh<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    ops::map_elements(a, T::tanh)
+}
+
+pub fn relu<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    // NaN passes through rather than being clipped to 0
+    ops::map_elements(a, |x| {
+        if x.is_nan() || x > T::zero() {
+            x
+        } else {
+            T::zero()
+        }
+    })
+}
+
+// x for x > 0, slope * x otherwise
+pub fn leaky_relu<T: RealField>(a: &impl AsView<T>, slope: T) -> Matrix<T> {
+    ops::map_elements(a, |x| if x > T::zero() { x } else { slope * x })
+}
+
+// the tanh approximation used by BERT and GPT-2 (PyTorch's
+// approximate="tanh"), within about 1e-3 of x * Phi(x)
+pub fn gelu<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    let c = T::from_f64((2.0 / std::f64::consts::PI).sqrt());
+    let k = T::from_f64(0.044715);
+    let half = T::from_f64(0.5);
+    ops::map_elements(a, |x| {
+        half * x * (T::one() + (c * (x + k * x * x * x)).tanh())
+    })
+}
+
+// ln(1 + e^x), written as max(x, 0) + ln(1 + e^-|x|) so large |x| neither
+// overflows nor loses the small term
+pub fn softplus<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    ops::map_elements(a, |x| x.max(T::zero()) + (-x.abs()).exp().ln_1p())
+}
+
+// 1 / (1 + e^-x), evaluated through e^-|x| so neither tail overflows
+pub fn sigmoid<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    ops::map_elements(a, |x| {
+        let e = (-x.abs()).exp();
+        if x >= T::zero() {
+            T::one() / (T::one() + e)
+        } else {
+            e / (T::one() + e)
+        }
+    })
+}
+
+// each row mapped to exp(x - max) / sum(exp(x - max)); subtracting the row
+// maximum keeps exp from overflowing without changing the result
+pub fn softmax<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    let mut out = a.as_view().to_matrix();
+    let cols = out.cols;
+    for row in out.data.chunks_mut(cols.max(1)) {
+        if let Some(count) = infinite_count(row) {
+            let share = T::one() / T::from_usize(count);
+            fill_infinite(row, share, T::zero());
+            continue;
+        }
+        let max = row_max(row);
+        let mut total = T::zero();
+        for x in row.iter_mut() {
+            *x = (*x - max).exp();
+            total += *x;
+        }
+        for x in row.iter_mut() {
+            *x /= total;
+        }
+    }
+    out
+}
+
+// ln(softmax(x)) per row, computed as x - max - ln(sum(exp(x - max))) so
+// strongly negative entries stay finite instead of becoming ln(0)
+pub fn log_softmax<T: RealField>(a: &impl AsView<T>) -> Matrix<T> {
+    let mut out = a.as_view().to_matrix();
+    let cols = out.cols;
+    for row in out.data.chunks_mut(cols.max(1)) {
+        if let Some(count) = infinite_count(row) {
+            let share = -T::from_usize(count).ln();
+            fill_infinite(row, share, T::NEG_INFINITY);
+            continue;
+        }
+        let max = row_max(row);
+        let log_total = row.iter().map(|&x| (x - max).exp()).sum::<T>().ln();
+        for x in row.iter_mut() {
+            *x = *x - max - log_total;
+        }
+    }
+    out
+}
+
+// a row reaching +inf puts all of its mass on the +inf entries, split evenly;
+// returns how many there are, or None when the shifted formula applies
+fn infinite_count<T: RealField>(row: &[T]) -> Option<usize> {
+    if row.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+    let count = row.iter().filter(|&&x| x == T::INFINITY).count();
+    (count > 0).then_some(count)
+}
+
+fn fill_infinite<T: RealField>(row: &mut [T], share: T, rest: T) {
+    for x in row.iter_mut() {
+        *x = if *x == T::INFINITY { share } else { rest };
+    }
+}
+
+// the shift for softmax; with +inf rows handled above, only an all -inf row
+// or one mixing NaN and +inf lacks a finite maximum, and those are left
+// unshifted so they come out NaN
+fn row_max<T: RealField>(row: &[T]) -> T {
+    let max = row.iter().copied().fold(T::NEG_INFINITY, T::max);
+    if max.is_finite() {
+        max
+    } else {
+        T::zero()
+    }
+}
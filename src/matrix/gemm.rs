@@ -0,0 +1,266 @@
+use crate::matrix::matrix::{Layout, Matrix};
+use crate::matrix::view::{AsView, MatrixView};
+use crate::utils::error::Error;
+use crate::utils::parallel;
+use crate::utils::scalar::Scalar;
+
+// register tile computed by one microkernel call
+const MR: usize = 6;
+const NR: usize = 8;
+// cache blocks: a KC x NR sliver of B stays in L1, the packed MC x KC block of
+// A in L2 and the packed KC x NC panel of B in L3
+const KC: usize = 256;
+const MC: usize = 96;
+const NC: usize = 2048;
+
+// acc[i * NR + j] = sum over p < k of a[p * MR + i] * b[p * NR + j], for
+// packed slivers a (k x MR) and b (k x NR)
+pub type Kernel<T> = fn(usize, &[T], &[T], &mut [T]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transpose {
+    No,
+    Yes,
+}
+
+// C = alpha * op(A) * op(B) + beta * C, where op(X) is X or X^T; C is
+// overwritten rather than read when beta is zero, as in BLAS
+pub fn gemm<T: Scalar>(
+    alpha: T,
+    a: &impl AsView<T>,
+    trans_a: Transpose,
+    b: &impl AsView<T>,
+    trans_b: Transpose,
+    beta: T,
+    c: &mut Matrix<T>,
+) -> Result<(), Error> {
+    let a = op_view(a.as_view(), trans_a);
+    let b = op_view(b.as_view(), trans_b);
+    let (m, k) = a.shape();
+    let (kb, n) = b.shape();
+    if k != kb {
+        return Err(Error::DimensionMismatch {
+            op: "gemm",
+            left: (m, k),
+            right: (kb, n),
+        });
+    }
+    if c.shape() != (m, n) {
+        return Err(Error::DimensionMismatch {
+            op: "gemm",
+            left: (m, n),
+            right: c.shape(),
+        });
+    }
+
+    // column-major C holds C^T row by row, so compute C^T = op(B)^T * op(A)^T
+    // in place instead
+    if c.layout == Layout::ColMajor {
+        let mut ct = std::mem::replace(c, Matrix::new(0, 0, Vec::new())).transpose();
+        let result = gemm(
+            alpha,
+            &b.t(),
+            Transpose::No,
+            &a.t(),
+            Transpose::No,
+            beta,
+            &mut ct,
+        );
+        *c = ct.transpose();
+        return result;
+    }
+
+    if beta == T::zero() {
+        c.data.iter_mut().for_each(|x| *x = T::zero());
+    } else if beta != T::one() {
+        c.data.iter_mut().for_each(|x| *x *= beta);
+    }
+    if alpha == T::zero() || k == 0 {
+        return Ok(());
+    }
+
+    let kernel = T::gemm_kernel();
+    let a_len = m.min(MC).div_ceil(MR) * MR * k.min(KC);
+    let mut packed_b = vec![T::zero(); n.min(NC).div_ceil(NR) * NR * k.min(KC)];
+
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+            pack_b(&b, pc, kc, jc, nc, &mut packed_b);
+            let packed_b = &packed_b;
+
+            // threads own disjoint row ranges of C and share the packed B panel
+            parallel::for_each_rows(&mut c.data, n, m * nc * kc, |first, c_rows| {
+                let mut packed_a = vec![T::zero(); a_len];
+                let mut tile = [T::zero(); MR * NR];
+                let rows_here = c_rows.len() / n;
+
+                for ic in (0..rows_here).step_by(MC) {
+                    let mc = MC.min(rows_here - ic);
+                    pack_a(&a, first + ic, mc, pc, kc, &mut packed_a);
+
+                    for jr in (0..nc).step_by(NR) {
+                        let b_sliver = &packed_b[jr * kc..(jr + NR) * kc];
+                        for ir in (0..mc).step_by(MR) {
+                            let a_sliver = &packed_a[ir * kc..(ir + MR) * kc];
+                            kernel(kc, a_sliver, b_sliver, &mut tile);
+
+                            // edge tiles were zero padded; only the valid part lands in C
+                            let rows = MR.min(mc - ir);
+                            let cols = NR.min(nc - jr);
+                            for i in 0..rows {
+                                let offset = (ic + ir + i) * n + jc + jr;
+                                let row = &mut c_rows[offset..offset + cols];
+                                for (x, &t) in row.iter_mut().zip(&tile[i * NR..i * NR + cols]) {
+                                    *x += alpha * t;
+                                }
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    }
+    Ok(())
+}
+
+fn op_view<T>(matrix: MatrixView<'_, T>, trans: Transpose) -> MatrixView<'_, T> {
+    match trans {
+        Transpose::No => matrix,
+        Transpose::Yes => matrix.t(),
+    }
+}
+
+// packs rows ic..ic + mc, columns pc..pc + kc of op(A) into MR-row slivers,
+// each stored column by column
+fn pack_a<T: Scalar>(
+    a: &MatrixView<T>,
+    ic: usize,
+    mc: usize,
+    pc: usize,
+    kc: usize,
+    packed: &mut [T],
+) {
+    for ir in (0..mc).step_by(MR) {
+        let sliver = &mut packed[ir * kc..(ir + MR) * kc];
+        let rows = MR.min(mc - ir);
+        for p in 0..kc {
+            for i in 0..MR {
+                sliver[p * MR + i] = if i < rows {
+                    a.at(ic + ir + i, pc + p)
+                } else {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+// packs rows pc..pc + kc, columns jc..jc + nc of op(B) into NR-column slivers,
+// each stored row by row
+fn pack_b<T: Scalar>(
+    b: &MatrixView<T>,
+    pc: usize,
+    kc: usize,
+    jc: usize,
+    nc: usize,
+    packed: &mut [T],
+) {
+    for jr in (0..nc).step_by(NR) {
+        let sliver = &mut packed[jr * kc..(jr + NR) * kc];
+        let cols = NR.min(nc - jr);
+        for p in 0..kc {
+            for j in 0..NR {
+                sliver[p * NR + j] = if j < cols {
+                    b.at(pc + p, jc + jr + j)
+                } else {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+// portable microkernel; the fixed tile size lets the compiler unroll and
+// vectorise it for any scalar type
+pub(crate) fn kernel_generic<T: Scalar>(k: usize, a: &[T], b: &[T], acc: &mut [T]) {
+    let mut c = [T::zero(); MR * NR];
+    for (a, b) in a.chunks_exact(MR).zip(b.chunks_exact(NR)).take(k) {
+        for i in 0..MR {
+            for j in 0..NR {
+                c[i * NR + j] += a[i] * b[j];
+            }
+        }
+    }
+    acc[..MR * NR].copy_from_slice(&c);
+}
+
+pub(crate) fn kernel_f64() -> Kernel<f64> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            // the CPU supports the instructions the kernel was compiled for
+            return |k, a, b, acc| unsafe { avx2::kernel_f64(k, a, b, acc) };
+        }
+    }
+    kernel_generic::<f64>
+}
+
+pub(crate) fn kernel_f32() -> Kernel<f32> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            return |k, a, b, acc| unsafe { avx2::kernel_f32(k, a, b, acc) };
+        }
+    }
+    kernel_generic::<f32>
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use super::{MR, NR};
+    use std::arch::x86_64::*;
+
+    // 6 x 8 tile in twelve ymm accumulators, two B loads and one broadcast
+    // per row and step
+    #[target_feature(enable = "avx2,fma")]
+    pub(super) unsafe fn kernel_f64(k: usize, a: &[f64], b: &[f64], acc: &mut [f64]) {
+        assert!(a.len() >= k * MR && b.len() >= k * NR && acc.len() >= MR * NR);
+        let (a, b) = (a.as_ptr(), b.as_ptr());
+        let mut c = [[_mm256_setzero_pd(); 2]; MR];
+        for p in 0..k {
+            let b0 = _mm256_loadu_pd(b.add(p * NR));
+            let b1 = _mm256_loadu_pd(b.add(p * NR + 4));
+            for (i, row) in c.iter_mut().enumerate() {
+                let ai = _mm256_broadcast_sd(&*a.add(p * MR + i));
+                row[0] = _mm256_fmadd_pd(ai, b0, row[0]);
+                row[1] = _mm256_fmadd_pd(ai, b1, row[1]);
+            }
+        }
+        let out = acc.as_mut_ptr();
+        for (i, row) in c.iter().enumerate() {
+            _mm256_storeu_pd(out.add(i * NR), row[0]);
+            _mm256_storeu_pd(out.add(i * NR + 4), row[1]);
+        }
+    }
+
+    // eight f32 lanes cover a full row of the tile
+    #[target_feature(enable = "avx2,fma")]
+    pub(super) unsafe fn kernel_f32(k: usize, a: &[f32], b: &[f32], acc: &mut [f32]) {
+        assert!(a.len() >= k * MR && b.len() >= k * NR && acc.len() >= MR * NR);
+        let (a, b) = (a.as_ptr(), b.as_ptr());
+        let mut c = [_mm256_setzero_ps(); MR];
+        for p in 0..k {
+            let bp = _mm256_loadu_ps(b.add(p * NR));
+            for (i, row) in c.iter_mut().enumerate() {
+                let ai = _mm256_broadcast_ss(&*a.add(p * MR + i));
+                *row = _mm256_fmadd_ps(ai, bp, *row);
+            }
+        }
+        let out = acc.as_mut_ptr();
+        for (i, row) in c.iter().enumerate() {
+            _mm256_storeu_ps(out.add(i * NR), *row);
+        }
+    }
+}
@@ -1,46 +1,304 @@
+use std::borrow::Cow;
 use std::fmt;
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Matrix {
+use crate::utils::error::Error;
+use crate::utils::scalar::{RealField, Scalar};
+
+// order of the elements in `data`: row by row (C style) or column by column
+// (Fortran/LAPACK style)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    #[default]
+    RowMajor,
+    ColMajor,
+}
+
+impl Layout {
+    pub fn flip(self) -> Layout {
+        match self {
+            Layout::RowMajor => Layout::ColMajor,
+            Layout::ColMajor => Layout::RowMajor,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Matrix<T = f64> {
     pub rows: usize,
     pub cols: usize,
-    pub data: Vec<f64>,
+    pub data: Vec<T>,
+    pub layout: Layout,
 }
 
-impl Matrix {
-    pub fn new(rows: usize, cols: usize, data: Vec<f64>) -> Self {
-        Self { rows, cols, data }
+impl<T> Matrix<T> {
+    // (rows, cols)
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
     }
 
-    pub fn zeros(rows: usize, cols: usize) -> Self {
-        Self {
+    // (row stride, column stride) of `data`
+    pub fn strides(&self) -> (usize, usize) {
+        match self.layout {
+            Layout::RowMajor => (self.cols, 1),
+            Layout::ColMajor => (1, self.rows),
+        }
+    }
+
+    fn check_index(&self, row: usize, col: usize) -> Result<(), Error> {
+        if row < self.rows && col < self.cols {
+            Ok(())
+        } else {
+            Err(Error::IndexOutOfBounds {
+                index: (row, col),
+                shape: self.shape(),
+            })
+        }
+    }
+
+    pub(crate) fn offset(&self, row: usize, col: usize) -> usize {
+        match self.layout {
+            Layout::RowMajor => row * self.cols + col,
+            Layout::ColMajor => col * self.rows + row,
+        }
+    }
+
+    // (row, col) of data[k]
+    pub(crate) fn position(&self, k: usize) -> (usize, usize) {
+        match self.layout {
+            Layout::RowMajor => (k / self.cols, k % self.cols),
+            Layout::ColMajor => (k % self.rows, k / self.rows),
+        }
+    }
+
+    // O(1): the same buffer read in the other order
+    pub fn transpose(self) -> Matrix<T> {
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data: self.data,
+            layout: self.layout.flip(),
+        }
+    }
+}
+
+// `data` must hold exactly rows * cols elements
+fn check_len(rows: usize, cols: usize, len: usize) -> Result<(), Error> {
+    if rows.checked_mul(cols) == Some(len) {
+        Ok(())
+    } else {
+        Err(Error::DataLength {
+            shape: (rows, cols),
+            len,
+        })
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
+    // panics when data.len() != rows * cols; see try_new
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        Self::try_new(rows, cols, data).unwrap_or_else(|e| panic!("Matrix::new: {}", e))
+    }
+
+    pub fn try_new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, Error> {
+        check_len(rows, cols, data.len())?;
+        Ok(Self {
             rows,
             cols,
-            data: vec![0.0; rows * cols],
-        }
+            data,
+            layout: Layout::RowMajor,
+        })
+    }
+
+    // wraps data stored column by column, e.g. from Fortran or LAPACK, without
+    // copying it; panics when data.len() != rows * cols
+    pub fn from_col_major(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        Self::try_from_col_major(rows, cols, data)
+            .unwrap_or_else(|e| panic!("Matrix::from_col_major: {}", e))
+    }
+
+    pub fn try_from_col_major(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, Error> {
+        check_len(rows, cols, data.len())?;
+        Ok(Self {
+            rows,
+            cols,
+            data,
+            layout: Layout::ColMajor,
+        })
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix::new(rows, cols, vec![T::zero(); rows * cols])
     }
 
     pub fn identity(n: usize) -> Self {
-        let mut data = vec![0.0; n * n];
+        let mut data = vec![T::zero(); n * n];
         for i in 0..n {
-            data[i * n + i] = 1.0;
+            data[i * n + i] = T::one();
         }
-        Self {
-            rows: n,
-            cols: n,
-            data,
+        Matrix::new(n, n, data)
+    }
+
+    pub fn ones(rows: usize, cols: usize) -> Self {
+        Matrix::full(rows, cols, T::one())
+    }
+
+    pub fn full(rows: usize, cols: usize, value: T) -> Self {
+        Matrix::new(rows, cols, vec![value; rows * cols])
+    }
+
+    // element (i, j) is f(i, j), evaluated in row-major order
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
         }
+        Matrix::new(rows, cols, data)
     }
 
-    pub fn get(&self, row: usize, col: usize) -> f64 {
-        self.data[row * self.cols + col]
+    // one slice per row; fails on ragged rows
+    pub fn from_rows<R: AsRef<[T]>>(rows: &[R]) -> Result<Self, Error> {
+        let cols = rows.first().map_or(0, |row| row.as_ref().len());
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for row in rows {
+            let row = row.as_ref();
+            if row.len() != cols {
+                return Err(Error::DimensionMismatch {
+                    op: "from_rows",
+                    left: (1, cols),
+                    right: (1, row.len()),
+                });
+            }
+            data.extend_from_slice(row);
+        }
+        Ok(Matrix::new(rows.len(), cols, data))
+    }
+
+    // one slice per column; fails on ragged columns
+    pub fn from_cols<C: AsRef<[T]>>(cols: &[C]) -> Result<Self, Error> {
+        let rows = cols.first().map_or(0, |col| col.as_ref().len());
+        let mut data = Vec::with_capacity(rows * cols.len());
+        for col in cols {
+            let col = col.as_ref();
+            if col.len() != rows {
+                return Err(Error::DimensionMismatch {
+                    op: "from_cols",
+                    left: (rows, 1),
+                    right: (col.len(), 1),
+                });
+            }
+            data.extend_from_slice(col);
+        }
+        Ok(Matrix::from_col_major(rows, cols.len(), data))
+    }
+
+    // square matrix with `diag` on its diagonal
+    pub fn from_diag(diag: &[T]) -> Self {
+        let n = diag.len();
+        Matrix::from_fn(n, n, |i, j| if i == j { diag[i] } else { T::zero() })
+    }
+
+    // ones on the k-th diagonal: k = 0 is the main diagonal, k > 0 above it
+    // and k < 0 below it
+    pub fn eye(rows: usize, cols: usize, k: isize) -> Self {
+        Matrix::from_fn(rows, cols, |i, j| {
+            if j as isize - i as isize == k {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    // bounds are only checked in debug builds; in release an out-of-range
+    // column can silently read another row, so use try_get for untrusted
+    // indices
+    pub fn get(&self, row: usize, col: usize) -> T {
+        debug_assert!(
+            row < self.rows && col < self.cols,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        self.data[self.offset(row, col)]
     }
 
-    pub fn set(&mut self, row: usize, col: usize, value: f64) {
-        self.data[row * self.cols + col] = value;
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        debug_assert!(
+            row < self.rows && col < self.cols,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        let k = self.offset(row, col);
+        self.data[k] = value;
     }
 
-    pub fn minor(&self, row: usize, col: usize) -> Matrix {
+    pub fn try_get(&self, row: usize, col: usize) -> Result<T, Error> {
+        self.check_index(row, col)?;
+        Ok(self.data[self.offset(row, col)])
+    }
+
+    pub fn try_set(&mut self, row: usize, col: usize, value: T) -> Result<(), Error> {
+        self.check_index(row, col)?;
+        let k = self.offset(row, col);
+        self.data[k] = value;
+        Ok(())
+    }
+
+    /// For inner loops whose bounds already guarantee the index is valid.
+    ///
+    /// # Safety
+    /// `row < self.rows`, `col < self.cols` and `data` holds rows * cols
+    /// elements.
+    pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> T {
+        *self.data.get_unchecked(self.offset(row, col))
+    }
+
+    /// # Safety
+    /// As for get_unchecked.
+    pub unsafe fn set_unchecked(&mut self, row: usize, col: usize, value: T) {
+        let k = self.offset(row, col);
+        *self.data.get_unchecked_mut(k) = value;
+    }
+
+    // the same matrix stored in `layout` order
+    pub fn to_layout(&self, layout: Layout) -> Matrix<T> {
+        if layout == self.layout {
+            return self.clone();
+        }
+        let (rows, cols) = (self.rows, self.cols);
+        let data = match layout {
+            Layout::RowMajor => (0..rows * cols)
+                .map(|k| self.get(k / cols, k % cols))
+                .collect(),
+            Layout::ColMajor => (0..rows * cols)
+                .map(|k| self.get(k % rows, k / rows))
+                .collect(),
+        };
+        Matrix {
+            rows,
+            cols,
+            data,
+            layout,
+        }
+    }
+
+    // borrows the matrix when it is already row-major, for code that indexes
+    // `data` directly
+    pub fn to_row_major(&self) -> Cow<'_, Matrix<T>> {
+        match self.layout {
+            Layout::RowMajor => Cow::Borrowed(self),
+            Layout::ColMajor => Cow::Owned(self.to_layout(Layout::RowMajor)),
+        }
+    }
+
+    pub fn minor(&self, row: usize, col: usize) -> Matrix<T> {
         let mut data = Vec::new();
         for r in 0..self.rows {
             if r == row {
@@ -55,15 +313,86 @@ impl Matrix {
         }
         Matrix::new(self.rows - 1, self.cols - 1, data)
     }
+
+    // converts every element, e.g. f64 -> f32 or real -> complex
+    pub fn cast<U: Scalar>(&self, convert: impl Fn(T) -> U) -> Matrix<U> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|&x| convert(x)).collect(),
+            layout: self.layout,
+        }
+    }
+}
+
+impl<T: RealField> Matrix<T> {
+    // column vector of n evenly spaced values from start to stop inclusive
+    pub fn linspace(start: T, stop: T, n: usize) -> Self {
+        let step = if n > 1 {
+            (stop - start) / T::from_usize(n - 1)
+        } else {
+            T::zero()
+        };
+        let mut data: Vec<T> = (0..n).map(|i| start + step * T::from_usize(i)).collect();
+        // hit stop exactly instead of accumulating rounding error
+        if n > 1 {
+            data[n - 1] = stop;
+        }
+        Matrix::new(n, 1, data)
+    }
+
+    // column vector start, start + step, ... up to but excluding stop
+    pub fn arange(start: T, stop: T, step: T) -> Result<Self, Error> {
+        if !(start.is_finite() && stop.is_finite() && step.is_finite()) {
+            return Err(Error::InvalidArgument {
+                op: "arange",
+                reason: "start, stop and step must be finite",
+            });
+        }
+        if step == T::zero() {
+            return Err(Error::InvalidArgument {
+                op: "arange",
+                reason: "step must be non-zero",
+            });
+        }
+        let count = ((stop - start) / step).to_f64().ceil();
+        // checked before allocating; `as usize` would saturate instead
+        let limit = (isize::MAX as usize / std::mem::size_of::<T>()) as f64;
+        if count.is_nan() || count > limit {
+            return Err(Error::InvalidArgument {
+                op: "arange",
+                reason: "too many elements",
+            });
+        }
+        let n = if count > 0.0 { count as usize } else { 0 };
+        Ok(Matrix::new(
+            n,
+            1,
+            (0..n).map(|i| start + step * T::from_usize(i)).collect(),
+        ))
+    }
+}
+
+// matrices are equal when their elements are, whatever order they are stored in
+impl<T: Scalar> PartialEq for Matrix<T> {
+    fn eq(&self, other: &Matrix<T>) -> bool {
+        if self.shape() != other.shape() {
+            return false;
+        }
+        if self.layout == other.layout {
+            return self.data == other.data;
+        }
+        (0..self.rows).all(|i| (0..self.cols).all(|j| self.get(i, j) == other.get(i, j)))
+    }
 }
 
-impl fmt::Display for Matrix {
+impl<T: Scalar> fmt::Display for Matrix<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for i in 0..self.rows {
             for j in 0..self.cols {
-                write!(f, "{} ", self.data[i * self.cols + j])?;
+                write!(f, "{} ", self.get(i, j))?;
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
         Ok(())
     }
@@ -1,3 +1,11 @@
 pub mod decompositions;
+pub mod elementwise;
+pub mod gemm;
+#[allow(clippy::module_inception)]
 pub mod matrix;
+pub mod norms;
+pub mod operators;
 pub mod ops;
+pub mod reductions;
+pub mod sparse;
+pub mod view;
@@ -0,0 +1,120 @@
+use crate::matrix::decompositions::{self, LuFactor};
+use crate::matrix::matrix::Matrix;
+use crate::matrix::view::{AsView, MatrixView};
+use crate::utils::error::Error;
+use crate::utils::parallel;
+use crate::utils::scalar::RealField;
+
+// Matrix norms take any view; the spectral and nuclear norms and cond_2 need
+// singular values, so like the factorizations they take a Matrix.
+
+// largest absolute column sum
+pub fn norm_1<T: RealField>(a: &impl AsView<T>) -> T {
+    let a = a.as_view();
+    (0..a.cols)
+        .map(|j| a.col(j).iter().map(|x| x.abs()).sum::<T>())
+        .fold(T::zero(), nan_max)
+}
+
+// largest absolute row sum
+pub fn norm_inf<T: RealField>(a: &impl AsView<T>) -> T {
+    let a = a.as_view();
+    (0..a.rows)
+        .map(|i| a.row(i).iter().map(|x| x.abs()).sum::<T>())
+        .fold(T::zero(), nan_max)
+}
+
+// T::max drops NaN; a NaN element should make the norm NaN, as in reductions::max
+fn nan_max<T: RealField>(acc: T, x: T) -> T {
+    if x > acc || x.is_nan() {
+        x
+    } else {
+        acc
+    }
+}
+
+// square root of the sum of squares of every element
+pub fn norm_fro<T: RealField>(a: &impl AsView<T>) -> T {
+    let a = a.as_view();
+    parallel::map_reduce_range(
+        a.rows * a.cols,
+        1,
+        |range| range.map(|k| a.flat(k) * a.flat(k)).sum::<T>(),
+        |x, y| x + y,
+    )
+    .sqrt()
+}
+
+// largest absolute element; not submultiplicative, so not a true matrix norm
+pub fn norm_max<T: RealField>(a: &impl AsView<T>) -> T {
+    a.as_view().iter().map(|x| x.abs()).fold(T::zero(), nan_max)
+}
+
+// largest singular value
+pub fn norm_2<T: RealField>(a: &Matrix<T>) -> Result<T, Error> {
+    let (_, s, _) = decompositions::svd(a)?;
+    Ok(s.first().copied().unwrap_or(T::zero()))
+}
+
+// sum of the singular values
+pub fn norm_nuclear<T: RealField>(a: &Matrix<T>) -> Result<T, Error> {
+    let (_, s, _) = decompositions::svd(a)?;
+    Ok(s.into_iter().sum())
+}
+
+// (sum |x_i|^p)^(1/p) of a 1xN or Nx1 vector for p >= 1, with p = infinity
+// giving the largest |x_i|
+pub fn vector_norm<T: RealField>(v: &impl AsView<T>, p: T) -> Result<T, Error> {
+    if p.is_nan() || p < T::one() {
+        return Err(Error::InvalidArgument {
+            op: "vector_norm",
+            reason: "p must be at least 1",
+        });
+    }
+    let v = v.as_view();
+    if v.rows != 1 && v.cols != 1 {
+        return Err(Error::NotAVector {
+            op: "vector_norm",
+            shape: v.shape(),
+        });
+    }
+
+    let largest = norm_max(&v);
+    if p == T::INFINITY || largest == T::zero() || !largest.is_finite() {
+        return Ok(largest);
+    }
+    if p == T::one() {
+        return Ok(v.iter().map(|x| x.abs()).sum());
+    }
+    // scaling by the largest element keeps |x_i|^p from overflowing
+    let sum = scaled_powers(&v, largest, p);
+    Ok(largest * sum.powf(T::one() / p))
+}
+
+fn scaled_powers<T: RealField>(v: &MatrixView<T>, scale: T, p: T) -> T {
+    if p == T::from_f64(2.0) {
+        v.iter().map(|x| (x / scale) * (x / scale)).sum()
+    } else {
+        v.iter().map(|x| (x.abs() / scale).powf(p)).sum()
+    }
+}
+
+// exact 2-norm condition number max(s) / min(s); infinite when A is rank
+// deficient, and 1 for an empty matrix like the identity it trivially is
+pub fn cond_2<T: RealField>(a: &Matrix<T>) -> Result<T, Error> {
+    let (_, s, _) = decompositions::svd(a)?;
+    match (s.first(), s.last()) {
+        // also covers the zero matrix, where largest / smallest would be 0 / 0
+        (_, Some(&smallest)) if smallest == T::zero() => Ok(T::INFINITY),
+        (Some(&largest), Some(&smallest)) => Ok(largest / smallest),
+        _ => Ok(T::one()),
+    }
+}
+
+// O(n^2) estimate of the 1-norm condition number after an O(n^3) LU
+// factorization, much cheaper than cond_2 and usually within a factor of 3 of
+// the exact value; infinite for singular matrices. Reuse an existing LuFactor
+// through LuFactor::cond1_estimate.
+pub fn cond_1_estimate<T: RealField>(a: &Matrix<T>) -> Result<T, Error> {
+    Ok(LuFactor::new(a)?.cond1_estimate())
+}
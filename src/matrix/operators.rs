@@ -0,0 +1,253 @@
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use crate::matrix::matrix::Matrix;
+use crate::matrix::ops;
+use crate::utils::complex::Complex;
+use crate::utils::error::Error;
+use crate::utils::scalar::{RealField, Scalar};
+
+// Operator overloads for Matrix. `+` and `-` are element-wise and broadcast
+// like `ops::add`, `*` between two matrices is the matrix product (use
+// `hadamard` for the element-wise one) and scalars may appear on either side of
+// `+`, `-` and `*`. The operators panic on a shape mismatch; the functions in
+// `ops` are the checked variants returning Result.
+
+fn expect_shape<T>(result: Result<T, Error>, op: &str) -> T {
+    result.unwrap_or_else(|e| panic!("{} failed: {}", op, e))
+}
+
+impl<T: Scalar> Matrix<T> {
+    // element-wise product, panics on a shape mismatch (see ops::hadamard)
+    pub fn hadamard(&self, other: &Matrix<T>) -> Matrix<T> {
+        expect_shape(ops::hadamard(self, other), "hadamard product")
+    }
+}
+
+// matrix (op) matrix for every combination of owned and borrowed operands
+macro_rules! impl_matrix_op {
+    ($trait:ident, $method:ident, $checked:path, $name:expr) => {
+        impl<T: Scalar> $trait<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                expect_shape($checked(self, rhs), $name)
+            }
+        }
+
+        impl<T: Scalar> $trait<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<T: Scalar> $trait<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T: Scalar> $trait<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_matrix_op!(Add, add, ops::add, "matrix addition");
+impl_matrix_op!(Sub, sub, ops::sub, "matrix subtraction");
+impl_matrix_op!(Mul, mul, ops::mul, "matrix multiplication");
+
+// in-place forms: the right operand may broadcast, but the left one keeps its
+// shape
+macro_rules! impl_elementwise_assign {
+    ($trait:ident, $method:ident, $op:tt, $op_name:expr, $name:expr) => {
+        impl<T: Scalar> $trait<&Matrix<T>> for Matrix<T> {
+            fn $method(&mut self, rhs: &Matrix<T>) {
+                let broadcast = ops::broadcast_dims($op_name, self.shape(), rhs.shape());
+                if expect_shape(broadcast, $name) != self.shape() {
+                    let error = Error::DimensionMismatch {
+                        op: $op_name,
+                        left: self.shape(),
+                        right: rhs.shape(),
+                    };
+                    panic!("{} failed: {}", $name, error);
+                }
+                let rhs = rhs.view();
+                for k in 0..self.data.len() {
+                    let (i, j) = self.position(k);
+                    self.data[k] $op ops::broadcast_get(&rhs, i, j);
+                }
+            }
+        }
+
+        impl<T: Scalar> $trait<Matrix<T>> for Matrix<T> {
+            fn $method(&mut self, rhs: Matrix<T>) {
+                self.$method(&rhs);
+            }
+        }
+    };
+}
+
+impl_elementwise_assign!(AddAssign, add_assign, +=, "add_assign", "matrix addition");
+impl_elementwise_assign!(SubAssign, sub_assign, -=, "sub_assign", "matrix subtraction");
+
+impl<T: Scalar> MulAssign<&Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<T: Scalar> MulAssign<Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl<T: Scalar> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        -self.clone()
+    }
+}
+
+impl<T: Scalar> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Matrix<T> {
+        self.data.iter_mut().for_each(|x| *x = -*x);
+        self
+    }
+}
+
+// matrix (op) scalar, applied to every element
+macro_rules! impl_scalar_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<T: Scalar> $assign_trait<T> for Matrix<T> {
+            fn $assign_method(&mut self, rhs: T) {
+                self.data.iter_mut().for_each(|x| *x $op rhs);
+            }
+        }
+
+        impl<T: Scalar> $trait<T> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(mut self, rhs: T) -> Matrix<T> {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl<T: Scalar> $trait<T> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: T) -> Matrix<T> {
+                self.clone().$method(rhs)
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Add, add, AddAssign, add_assign, +=);
+impl_scalar_op!(Sub, sub, SubAssign, sub_assign, -=);
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign, *=);
+impl_scalar_op!(Div, div, DivAssign, div_assign, /=);
+
+// scalar (op) matrix; coherence rules need a concrete left-hand type
+macro_rules! impl_scalar_lhs {
+    ($([$($generic:tt)*] $t:ty),*) => {
+        $(
+            impl<$($generic)*> Add<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn add(self, rhs: Matrix<$t>) -> Matrix<$t> {
+                    rhs + self
+                }
+            }
+
+            impl<$($generic)*> Add<&Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn add(self, rhs: &Matrix<$t>) -> Matrix<$t> {
+                    rhs + self
+                }
+            }
+
+            impl<$($generic)*> Sub<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn sub(self, rhs: Matrix<$t>) -> Matrix<$t> {
+                    -rhs + self
+                }
+            }
+
+            impl<$($generic)*> Sub<&Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn sub(self, rhs: &Matrix<$t>) -> Matrix<$t> {
+                    -rhs + self
+                }
+            }
+
+            impl<$($generic)*> Mul<Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: Matrix<$t>) -> Matrix<$t> {
+                    rhs * self
+                }
+            }
+
+            impl<$($generic)*> Mul<&Matrix<$t>> for $t {
+                type Output = Matrix<$t>;
+
+                fn mul(self, rhs: &Matrix<$t>) -> Matrix<$t> {
+                    rhs * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_lhs!([] f32, [] f64, [T: RealField] Complex<T>);
+
+// panics when either index is out of range; a bare bounds check on the flat
+// buffer would let (0, cols) silently read (1, 0)
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        &self.data[self.offset(row, col)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        let k = self.offset(row, col);
+        &mut self.data[k]
+    }
+}
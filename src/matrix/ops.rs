@@ -1,293 +1,460 @@
-use crate::matrix::matrix::Matrix;
+use crate::matrix::decompositions;
+use crate::matrix::decompositions::triangular::{
+    column, set_column, solve_lower, solve_upper, solve_upper_transpose,
+};
+use crate::matrix::decompositions::{CholeskyFactor, LuFactor, QrFactor};
+use crate::matrix::gemm::{gemm, Transpose};
+use crate::matrix::matrix::{Layout, Matrix};
+use crate::matrix::view::{AsView, MatrixView};
+use crate::utils::complex::Complex;
 use crate::utils::error::Error;
+use crate::utils::parallel;
+use crate::utils::scalar::{RealField, Scalar};
+
+// Element-wise operations, products, transposes and the vector operations take
+// any `AsView` operand, so rows, columns and blocks of a matrix can be passed
+// without copying. Factorization-based functions take a Matrix since they copy
+// it into the factor anyway.
+
+// element-wise operations broadcast like NumPy: each dimension must either
+// match or be 1 on one side, so a row vector, a column vector or a 1x1
+// matrix is repeated across the other operand
+pub fn broadcast_shape<T>(a: &impl AsView<T>, b: &impl AsView<T>) -> Result<(usize, usize), Error> {
+    broadcast_dims("broadcast", a.as_view().shape(), b.as_view().shape())
+}
 
-pub fn add(a: &Matrix, b: &Matrix) -> Result<Matrix, Error> {
-    if a.rows != b.rows || a.cols != b.cols {
-        return Err(Error::MatrixSizeMismatch);
-    }
-    let mut result = Matrix::zeros(a.rows, a.cols);
-    for i in 0..a.rows {
-        for j in 0..a.cols {
-            result.set(i, j, a.get(i, j) + b.get(i, j));
-        }
+// broadcast_shape on raw shapes, reporting failures against `op`
+pub(crate) fn broadcast_dims(
+    op: &'static str,
+    a: (usize, usize),
+    b: (usize, usize),
+) -> Result<(usize, usize), Error> {
+    let dim = |x: usize, y: usize| match (x, y) {
+        _ if x == y => Some(x),
+        (1, _) => Some(y),
+        (_, 1) => Some(x),
+        _ => None,
+    };
+    match (dim(a.0, b.0), dim(a.1, b.1)) {
+        (Some(rows), Some(cols)) => Ok((rows, cols)),
+        _ => Err(Error::DimensionMismatch {
+            op,
+            left: a,
+            right: b,
+        }),
     }
-    Ok(result)
 }
 
-// naive matrix mult
-pub fn mul(a: &Matrix, b: &Matrix) -> Result<Matrix, Error> {
-    if a.cols != b.rows {
-        return Err(Error::MatrixSizeMismatch);
-    }
-    let mut result = Matrix::zeros(a.rows, b.cols);
-    for i in 0..a.rows {
-        for j in 0..b.cols {
-            let mut sum = 0.0;
-            for k in 0..a.cols {
-                sum += a.get(i, k) * b.get(k, j);
-            }
-            result.set(i, j, sum);
+// element (i, j) of the broadcast shape, read from `view`
+pub(crate) fn broadcast_get<T: Copy>(view: &MatrixView<T>, i: usize, j: usize) -> T {
+    let i = if view.rows == 1 { 0 } else { i };
+    let j = if view.cols == 1 { 0 } else { j };
+    view[(i, j)]
+}
+
+pub(crate) fn broadcast_with<T: Scalar, U: Scalar>(
+    op: &'static str,
+    a: &impl AsView<T>,
+    b: &impl AsView<T>,
+    f: impl Fn(T, T) -> U + Sync,
+) -> Result<Matrix<U>, Error> {
+    let (a, b) = (a.as_view(), b.as_view());
+    let (rows, cols) = broadcast_dims(op, a.shape(), b.shape())?;
+    let mut result = Matrix::zeros(rows, cols);
+    match (a.storage(), b.storage()) {
+        // operands stored alike are walked in memory order, keeping their layout
+        (Some((layout, x)), Some((other, y))) if layout == other && a.shape() == b.shape() => {
+            result.layout = layout;
+            parallel::fill(&mut result.data, |k| f(x[k], y[k]));
         }
+        _ => parallel::fill(&mut result.data, |k| {
+            let (i, j) = (k / cols, k % cols);
+            f(broadcast_get(&a, i, j), broadcast_get(&b, i, j))
+        }),
     }
     Ok(result)
 }
 
-pub fn sub(a: &Matrix, b: &Matrix) -> Result<Matrix, Error> {
-    if a.rows != b.rows || a.cols != b.cols {
-        return Err(Error::MatrixSizeMismatch);
-    }
-    let mut result = Matrix::zeros(a.rows, a.cols);
-    for i in 0..a.rows {
-        for j in 0..a.cols {
-            result.set(i, j, a.get(i, j) - b.get(i, j));
+// applies f to every element
+pub(crate) fn map_elements<T: Scalar, U: Scalar>(
+    matrix: &impl AsView<T>,
+    f: impl Fn(T) -> U + Sync,
+) -> Matrix<U> {
+    let matrix = matrix.as_view();
+    let mut result = Matrix::zeros(matrix.rows, matrix.cols);
+    match matrix.storage() {
+        Some((layout, data)) => {
+            result.layout = layout;
+            parallel::fill(&mut result.data, |k| f(data[k]));
         }
+        None => parallel::fill(&mut result.data, |k| f(matrix.flat(k))),
     }
-    Ok(result)
+    result
 }
 
-pub fn div(a: &Matrix, b: &Matrix) -> Result<Matrix, Error> {
-    if a.rows != b.rows || a.cols != b.cols {
-        return Err(Error::MatrixSizeMismatch);
-    }
-    let mut result = Matrix::zeros(a.rows, a.cols);
-    for i in 0..a.rows {
-        for j in 0..a.cols {
-            result.set(i, j, a.get(i, j) / b.get(i, j));
-        }
+pub fn add<T: Scalar>(a: &impl AsView<T>, b: &impl AsView<T>) -> Result<Matrix<T>, Error> {
+    broadcast_with("add", a, b, |x, y| x + y)
+}
+
+// packed, cache-blocked product, see gemm
+pub fn mul<T: Scalar>(a: &impl AsView<T>, b: &impl AsView<T>) -> Result<Matrix<T>, Error> {
+    let (a_shape, b_shape) = (a.as_view().shape(), b.as_view().shape());
+    if a_shape.1 != b_shape.0 {
+        return Err(Error::DimensionMismatch {
+            op: "mul",
+            left: a_shape,
+            right: b_shape,
+        });
     }
+    let mut result = Matrix::zeros(a_shape.0, b_shape.1);
+    gemm(
+        T::one(),
+        a,
+        Transpose::No,
+        b,
+        Transpose::No,
+        T::zero(),
+        &mut result,
+    )?;
     Ok(result)
 }
 
-pub fn transpose(matrix: &Matrix) -> Matrix {
-    let mut result = Matrix::zeros(matrix.cols, matrix.rows);
-    for i in 0..matrix.rows {
-        for j in 0..matrix.cols {
-            result.set(j, i, matrix.get(i, j));
-        }
-    }
-    result
+pub fn sub<T: Scalar>(a: &impl AsView<T>, b: &impl AsView<T>) -> Result<Matrix<T>, Error> {
+    broadcast_with("sub", a, b, |x, y| x - y)
 }
 
-pub fn scalar_mul(matrix: &Matrix, scalar: f64) -> Matrix {
-    let mut result = Matrix::zeros(matrix.rows, matrix.cols);
-    for i in 0..matrix.rows {
-        for j in 0..matrix.cols {
-            result.set(i, j, matrix.get(i, j) * scalar);
-        }
+// element-wise product; `mul` (and `*`) is the matrix product
+pub fn hadamard<T: Scalar>(a: &impl AsView<T>, b: &impl AsView<T>) -> Result<Matrix<T>, Error> {
+    broadcast_with("hadamard", a, b, |x, y| x * y)
+}
+
+pub fn div<T: Scalar>(a: &impl AsView<T>, b: &impl AsView<T>) -> Result<Matrix<T>, Error> {
+    broadcast_with("div", a, b, |x, y| x / y)
+}
+
+// a row-major copy of the transpose; `Matrix::transpose` is the O(1) version
+// for an owned matrix
+pub fn transpose<T: Scalar>(matrix: &impl AsView<T>) -> Matrix<T> {
+    let matrix = matrix.as_view();
+    let (rows, cols) = matrix.shape();
+    // column-major storage already is the row-major transpose
+    if let Some((Layout::ColMajor, data)) = matrix.storage() {
+        return Matrix::new(cols, rows, data.to_vec());
     }
+    let mut result = Matrix::zeros(cols, rows);
+    // each thread writes whole rows of the result, i.e. columns of the input
+    parallel::for_each_rows(&mut result.data, rows, rows * cols, |first, out| {
+        for (r, row) in out.chunks_mut(rows).enumerate() {
+            for (i, x) in row.iter_mut().enumerate() {
+                *x = matrix.at(i, first + r);
+            }
+        }
+    });
     result
 }
 
-pub fn power(matrix: &Matrix, scalar: f64) -> Matrix {
-    let mut result = Matrix::zeros(matrix.rows, matrix.cols);
-    for i in 0..matrix.rows {
-        for j in 0..matrix.cols {
-            result.set(i, j, matrix.get(i, j).powf(scalar));
+pub fn scalar_mul<T: Scalar>(matrix: &impl AsView<T>, scalar: T) -> Matrix<T> {
+    map_elements(matrix, |x| x * scalar)
+}
+
+pub fn power<T: Scalar>(matrix: &impl AsView<T>, scalar: T::Real) -> Matrix<T> {
+    map_elements(matrix, |x| x.powf(scalar))
+}
+
+// determinant via pivoted LU in O(n^3)
+pub fn determinant<T: RealField>(matrix: &Matrix<T>) -> Result<T, Error> {
+    Ok(LuFactor::new(matrix)?.determinant())
+}
+
+// (sign, ln|det|) so that det = sign * exp(ln|det|) without under- or overflow;
+// a singular matrix gives (0.0, -inf)
+pub fn log_abs_determinant<T: RealField>(matrix: &Matrix<T>) -> Result<(T, T), Error> {
+    let factor = LuFactor::new(matrix)?;
+    let mut sign = factor.permutation_sign();
+    let mut log_abs = T::zero();
+    for d in factor.pivots() {
+        if d == T::zero() {
+            return Ok((T::zero(), T::NEG_INFINITY));
         }
+        if d < T::zero() {
+            sign = -sign;
+        }
+        log_abs += d.abs().ln();
     }
-    result
+    Ok((sign, log_abs))
 }
 
-pub fn determinant(matrix: &Matrix) -> f64 {
-    if matrix.rows != matrix.cols {
-        panic!("Matrix must be square");
-    }
+pub fn eigenvalues<T: RealField>(matrix: &Matrix<T>) -> Result<Vec<Complex<T>>, Error> {
+    decompositions::eigenvalues(matrix)
+}
 
-    let n = matrix.rows;
+// treats reciprocal condition numbers below machine epsilon as singular to
+// working precision
+pub fn inv<T: RealField>(matrix: &Matrix<T>) -> Result<Matrix<T>, Error> {
+    inv_with_condition(matrix, T::EPSILON).map(|(inverse, _)| inverse)
+}
 
-    if n == 1 {
-        return matrix.get(0, 0);
+// inverse through a pivoted LU factorization together with an estimate of the
+// 1-norm condition number; fails with SingularMatrix on an exactly zero pivot
+// and with IllConditioned when the reciprocal condition number is below
+// `tolerance`
+pub fn inv_with_condition<T: RealField>(
+    matrix: &Matrix<T>,
+    tolerance: T,
+) -> Result<(Matrix<T>, T), Error> {
+    let factor = LuFactor::new(matrix)?;
+    if let Some(pivot) = factor.zero_pivot() {
+        return Err(Error::SingularMatrix { pivot });
     }
 
-    if n == 2 {
-        return matrix.get(0, 0) * matrix.get(1, 1) - matrix.get(0, 1) * matrix.get(1, 0);
+    let rcond = factor.rcond();
+    if rcond < tolerance {
+        return Err(Error::IllConditioned {
+            rcond: rcond.to_f64(),
+            tolerance: tolerance.to_f64(),
+        });
     }
+    Ok((factor.inverse()?, T::one() / rcond))
+}
 
-    let mut det = 0.0;
-    for col in 0..n {
-        let submatrix = matrix.minor(0, col);
-        let sign = if col % 2 == 0 { 1.0 } else { -1.0 };
-        det += sign * matrix.get(0, col) * determinant(&submatrix);
+// solves A * X = B, choosing the method from the shape and structure of A:
+// triangular substitution, Cholesky for symmetric positive-definite input,
+// pivoted LU for other square systems, QR least squares when overdetermined
+// and the minimum-norm solution when underdetermined
+pub fn solve<T: RealField>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, Error> {
+    if a.rows != b.rows {
+        return Err(Error::DimensionMismatch {
+            op: "solve",
+            left: a.shape(),
+            right: b.shape(),
+        });
     }
 
-    det
-}
+    if a.rows > a.cols {
+        return QrFactor::new(a)?.solve(b);
+    }
+    if a.rows < a.cols {
+        return solve_min_norm(a, b);
+    }
 
-pub fn eigenvalues(matrix: &Matrix) -> Vec<f64> {
-    if matrix.rows != matrix.cols {
-        panic!("Matrix must be square");
+    let upper = is_triangular(a, true);
+    let lower = is_triangular(a, false);
+    if upper || lower {
+        // the diagonal holds the eigenvalues, so pivots that are rounding noise
+        // next to the largest one make the system numerically singular
+        let largest = (0..a.rows).fold(T::zero(), |acc, i| acc.max(a.get(i, i).abs()));
+        let tol = largest * T::EPSILON * T::from_usize(a.rows);
+        if let Some(pivot) = (0..a.rows).find(|&i| a.get(i, i).abs() <= tol) {
+            return Err(Error::SingularMatrix { pivot });
+        }
+        // the substitutions index `data` directly
+        let a = a.to_row_major();
+        let mut x = Matrix::zeros(a.rows, b.cols);
+        for j in 0..b.cols {
+            let mut col = column(b, j);
+            if upper {
+                solve_upper(&a, &mut col, false);
+            } else {
+                solve_lower(&a, &mut col, false);
+            }
+            set_column(&mut x, j, &col);
+        }
+        return Ok(x);
     }
 
-    if matrix.rows == 2 {
-        let a = matrix.get(0, 0);
-        let d = matrix.get(1, 1);
-
-        let trace = a + d; // diagnal sum 2x2 only
-        let determinant = determinant(matrix);
-        let discriminant = trace * trace - 4.0 * determinant;
-
-        if discriminant >= 0.0 {
-            let sqrt_discriminant = discriminant.sqrt();
-            return vec![
-                (trace + sqrt_discriminant) / 2.0,
-                (trace - sqrt_discriminant) / 2.0,
-            ];
-        } else {
-            panic!("Complex eigenvalues not supported");
+    let symmetric = (0..a.rows).all(|i| (0..i).all(|j| a.get(i, j) == a.get(j, i)));
+    if symmetric && (0..a.rows).all(|i| a.get(i, i) > T::zero()) {
+        match CholeskyFactor::new(a) {
+            Ok(factor) => return factor.solve(b),
+            Err(Error::NotPositiveDefinite { .. }) => {}
+            Err(e) => return Err(e),
         }
     }
 
-    unimplemented!("Eigenvalue calculation for n > 2 is not implemented");
+    LuFactor::new(a)?.solve(b)
 }
 
-pub fn inv(matrix: &Matrix) -> Option<Matrix> {
-    assert_eq!(
-        matrix.rows, matrix.cols,
-        "Matrix must be square to compute inverse"
-    );
-
-    let n = matrix.rows;
-    let mut augmented = Matrix {
-        rows: n,
-        cols: 2 * n,
-        data: vec![0.0; n * 2 * n],
-    };
+// x = Q * R^-T * b from A^T = Q * R, the solution of smallest 2-norm
+fn solve_min_norm<T: RealField>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, Error> {
+    let (q, r) = decompositions::qr(&transpose(a))?;
+    if let Some(pivot) = decompositions::negligible_pivot(&r, r.rows, a.shape()) {
+        return Err(Error::SingularMatrix { pivot });
+    }
 
-    // init augmented matrix [A | I]
-    for i in 0..n {
-        for j in 0..n {
-            augmented.data[i * augmented.cols + j] = matrix.data[i * matrix.cols + j];
-        }
-        augmented.data[i * augmented.cols + (i + n)] = 1.0; // identity matrix here bc using the function was a wast of space!
+    let mut y = Matrix::zeros(r.rows, b.cols);
+    for j in 0..b.cols {
+        let mut col = column(b, j);
+        solve_upper_transpose(&r, &mut col, false);
+        set_column(&mut y, j, &col);
     }
+    mul(&q, &y)
+}
 
-    // Gauss-Jordan elim
-    for i in 0..n {
-        // find pivot row
-        let mut pivot_row = i;
-        for j in i + 1..n {
-            if augmented.data[j * augmented.cols + i].abs()
-                > augmented.data[pivot_row * augmented.cols + i].abs()
-            {
-                pivot_row = j;
-            }
-        }
+fn is_triangular<T: Scalar>(a: &Matrix<T>, upper: bool) -> bool {
+    (0..a.rows).all(|i| {
+        (0..a.cols)
+            .filter(|&j| if upper { j < i } else { j > i })
+            .all(|j| a.get(i, j) == T::zero())
+    })
+}
 
-        // swap rows if necessary
-        if pivot_row != i {
-            for j in 0..2 * n {
-                augmented
-                    .data
-                    .swap(i * augmented.cols + j, pivot_row * augmented.cols + j);
-            }
-        }
+// SVD-based least squares that also handles rank-deficient A. Singular values
+// below rcond * max(s) are treated as zero (default eps * max(m, n)). Returns
+// the minimum-norm solution, the squared residual norm of each column of B
+// (empty unless A is overdetermined and of full column rank), the effective
+// rank, and the singular values of A.
+#[allow(clippy::type_complexity)]
+pub fn lstsq<T: RealField>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    rcond: Option<T>,
+) -> Result<(Matrix<T>, Vec<T>, usize, Vec<T>), Error> {
+    if a.rows != b.rows {
+        return Err(Error::DimensionMismatch {
+            op: "lstsq",
+            left: a.shape(),
+            right: b.shape(),
+        });
+    }
 
-        // check for singular matrix
-        if augmented.data[i * augmented.cols + i] == 0.0 {
-            return None; // matrix is singular, no inverse exists
-        }
+    let (u, s, vt) = decompositions::svd(a)?;
+    let rcond = rcond.unwrap_or(T::EPSILON * T::from_usize(a.rows.max(a.cols)));
+    let cutoff = rcond * s.first().copied().unwrap_or(T::zero());
+    let rank = s.iter().take_while(|&&value| value > cutoff).count();
 
-        // normalize pivot row
-        let pivot = augmented.data[i * augmented.cols + i];
-        for j in 0..2 * n {
-            augmented.data[i * augmented.cols + j] /= pivot;
-        }
-
-        // eliminate all others
-        for k in 0..n {
-            if k != i {
-                let factor = augmented.data[k * augmented.cols + i];
-                for j in 0..2 * n {
-                    augmented.data[k * augmented.cols + j] -=
-                        factor * augmented.data[i * augmented.cols + j];
-                }
-            }
+    // x = V * diag(1 / s) * U^T * b over the first `rank` singular triplets
+    let utb = mul(&transpose(&u), b)?;
+    let mut scaled = Matrix::zeros(rank, b.cols);
+    for (i, &si) in s.iter().enumerate().take(rank) {
+        for j in 0..b.cols {
+            scaled.set(i, j, utb.get(i, j) / si);
         }
     }
-
-    // extract inverse matrix from the aug matrix
-    let mut inverse_data = vec![0.0; n * n];
-    for i in 0..n {
-        for j in 0..n {
-            // round to 6 decimal places
-            inverse_data[i * n + j] =
-                (augmented.data[i * augmented.cols + (j + n)] * 1e6).round() / 1e6;
+    let mut v = Matrix::zeros(a.cols, rank);
+    for i in 0..a.cols {
+        for k in 0..rank {
+            v.set(i, k, vt.get(k, i));
         }
     }
+    let x = mul(&v, &scaled)?;
+
+    let residuals = if a.rows > a.cols && rank == a.cols {
+        let r = sub(&mul(a, &x)?, b)?;
+        (0..b.cols)
+            .map(|j| (0..r.rows).map(|i| r.get(i, j).powi(2)).sum())
+            .collect()
+    } else {
+        Vec::new()
+    };
 
-    Some(Matrix {
-        rows: n,
-        cols: n,
-        data: inverse_data,
-    })
+    Ok((x, residuals, rank, s))
 }
 
 /*
 VECTOR OPS
 */
 
-pub fn dot(a: &Matrix, b: &Matrix) -> f64 {
-    assert!(
-        (a.rows == 1 || a.cols == 1) && (b.rows == 1 || b.cols == 1),
-        "Dot product requires 1xN or Nx1 vectors"
-    );
-    assert_eq!(a.rows * a.cols, b.rows * b.cols, "Vector sizes must match");
+// length of a 1xN or Nx1 vector
+fn vector_len<T>(vec: &MatrixView<T>, op: &'static str) -> Result<usize, Error> {
+    if vec.rows == 1 || vec.cols == 1 {
+        Ok(vec.rows * vec.cols)
+    } else {
+        Err(Error::NotAVector {
+            op,
+            shape: vec.shape(),
+        })
+    }
+}
+
+pub fn dot<T: RealField>(a: &impl AsView<T>, b: &impl AsView<T>) -> Result<T, Error> {
+    let (a, b) = (a.as_view(), b.as_view());
+    let len = vector_len(&a, "dot")?;
+    if vector_len(&b, "dot")? != len {
+        return Err(Error::DimensionMismatch {
+            op: "dot",
+            left: a.shape(),
+            right: b.shape(),
+        });
+    }
 
-    a.data.iter().zip(&b.data).map(|(x, y)| x * y).sum()
+    Ok(parallel::map_reduce_range(
+        len,
+        1,
+        |range| range.map(|k| a.flat(k) * b.flat(k)).sum(),
+        |x, y| x + y,
+    ))
 }
 
-pub fn magnitude(vec: &Matrix) -> f64 {
-    assert!(
-        vec.rows == 1 || vec.cols == 1,
-        "Magnitude requires a vector"
-    );
+pub fn magnitude<T: RealField>(vec: &impl AsView<T>) -> Result<T, Error> {
+    let vec = vec.as_view();
+    let len = vector_len(&vec, "magnitude")?;
 
-    vec.data.iter().map(|x| x * x).sum::<f64>().sqrt()
+    Ok(parallel::map_reduce_range(
+        len,
+        1,
+        |range| range.map(|k| vec.flat(k) * vec.flat(k)).sum::<T>(),
+        |x, y| x + y,
+    )
+    .sqrt())
 }
 
-pub fn normalize(vec: &Matrix) -> Matrix {
-    let mag = magnitude(vec);
-    assert!(mag != 0.0, "Cannot normalize a zero vector");
+pub fn normalize<T: RealField>(vec: &impl AsView<T>) -> Result<Matrix<T>, Error> {
+    let mag = magnitude(vec)?;
+    if mag == T::zero() {
+        return Err(Error::ZeroVector { op: "normalize" });
+    }
 
-    scalar_mul(vec, 1.0 / mag)
+    Ok(scalar_mul(vec, T::one() / mag))
 }
 
-pub fn cross(a: &Matrix, b: &Matrix) -> Matrix {
-    assert_eq!(a.rows * a.cols, 3, "Cross product requires 3D vectors");
-    assert_eq!(b.rows * b.cols, 3, "Cross product requires 3D vectors");
+pub fn cross<T: RealField>(a: &impl AsView<T>, b: &impl AsView<T>) -> Result<Matrix<T>, Error> {
+    let (a, b) = (a.as_view(), b.as_view());
+    for v in [&a, &b] {
+        let len = vector_len(v, "cross")?;
+        if len != 3 {
+            return Err(Error::VectorLength {
+                op: "cross",
+                expected: 3,
+                found: len,
+            });
+        }
+    }
 
-    let x1 = a.get(0, 0);
-    let y1 = a.get(1, 0);
-    let z1 = a.get(2, 0);
+    let x1 = a.flat(0);
+    let y1 = a.flat(1);
+    let z1 = a.flat(2);
 
-    let x2 = b.get(0, 0);
-    let y2 = b.get(1, 0);
-    let z2 = b.get(2, 0);
+    let x2 = b.flat(0);
+    let y2 = b.flat(1);
+    let z2 = b.flat(2);
 
-    Matrix::new(
+    Ok(Matrix::new(
         3,
         1,
         vec![y1 * z2 - z1 * y2, z1 * x2 - x1 * z2, x1 * y2 - y1 * x2],
-    )
+    ))
 }
 
-pub fn projection(a: &Matrix, b: &Matrix) -> Matrix {
-    let dot_product = dot(a, b);
-    let mag_b_sq = dot(b, b);
-    assert!(mag_b_sq != 0.0, "Cannot project onto a zero vector");
+pub fn projection<T: RealField>(
+    a: &impl AsView<T>,
+    b: &impl AsView<T>,
+) -> Result<Matrix<T>, Error> {
+    let dot_product = dot(a, b)?;
+    let mag_b_sq = dot(b, b)?;
+    if mag_b_sq == T::zero() {
+        return Err(Error::ZeroVector { op: "projection" });
+    }
 
-    scalar_mul(b, dot_product / mag_b_sq)
+    Ok(scalar_mul(b, dot_product / mag_b_sq))
 }
 
-pub fn angle(a: &Matrix, b: &Matrix) -> f64 {
-    let dot_product = dot(a, b);
-    let mag_a = magnitude(a);
-    let mag_b = magnitude(b);
-
-    assert!(
-        mag_a != 0.0 && mag_b != 0.0,
-        "Cannot compute angle with zero vector"
-    );
+pub fn angle<T: RealField>(a: &impl AsView<T>, b: &impl AsView<T>) -> Result<T, Error> {
+    let dot_product = dot(a, b)?;
+    let mag_a = magnitude(a)?;
+    let mag_b = magnitude(b)?;
+    if mag_a == T::zero() || mag_b == T::zero() {
+        return Err(Error::ZeroVector { op: "angle" });
+    }
 
-    (dot_product / (mag_a * mag_b)).acos()
+    Ok((dot_product / (mag_a * mag_b)).acos())
 }
@@ -0,0 +1,295 @@
+use crate::matrix::gemm::{gemm, Transpose};
+use crate::matrix::matrix::Matrix;
+use crate::matrix::ops;
+use crate::matrix::view::{AsView, MatrixView};
+use crate::utils::error::Error;
+use crate::utils::parallel;
+use crate::utils::scalar::{RealField, Scalar};
+
+// Reductions over a whole matrix return a scalar. The `_axis` variants keep
+// the result 2-D so it broadcasts back against the input, e.g.
+// `&x - mean_axis(&x, Axis::Rows)?` centres every column.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    // down the rows: one value per column, a 1 x cols row vector (NumPy's
+    // axis=0)
+    Rows,
+    // across the columns: one value per row, a rows x 1 column vector
+    // (axis=1)
+    Cols,
+}
+
+// the lanes an axis reduction runs over: the columns for Axis::Rows and the
+// rows for Axis::Cols
+fn lanes<'a, T>(a: &MatrixView<'a, T>, axis: Axis) -> impl Iterator<Item = MatrixView<'a, T>> {
+    let a = *a;
+    let count = match axis {
+        Axis::Rows => a.cols,
+        Axis::Cols => a.rows,
+    };
+    (0..count).map(move |k| match axis {
+        Axis::Rows => a.col(k),
+        Axis::Cols => a.row(k),
+    })
+}
+
+// shapes one value per lane as a row or column vector
+fn collapse<T: Scalar>(axis: Axis, data: Vec<T>) -> Matrix<T> {
+    match axis {
+        Axis::Rows => Matrix::new(1, data.len(), data),
+        Axis::Cols => Matrix::new(data.len(), 1, data),
+    }
+}
+
+fn check_len(a: &MatrixView<impl Sized>, needed: usize, op: &'static str) -> Result<usize, Error> {
+    let found = a.rows * a.cols;
+    if found < needed {
+        return Err(Error::TooFewElements { op, needed, found });
+    }
+    Ok(found)
+}
+
+// 0 for an empty matrix
+pub fn sum<T: Scalar>(a: &impl AsView<T>) -> T {
+    let a = a.as_view();
+    parallel::map_reduce_range(
+        a.rows * a.cols,
+        1,
+        |range| range.map(|k| a.flat(k)).sum(),
+        |x, y| x + y,
+    )
+}
+
+pub fn sum_axis<T: Scalar>(a: &impl AsView<T>, axis: Axis) -> Matrix<T> {
+    collapse(
+        axis,
+        lanes(&a.as_view(), axis).map(|lane| sum(&lane)).collect(),
+    )
+}
+
+// 1 for an empty matrix
+pub fn prod<T: Scalar>(a: &impl AsView<T>) -> T {
+    a.as_view().iter().fold(T::one(), |p, x| p * x)
+}
+
+pub fn prod_axis<T: Scalar>(a: &impl AsView<T>, axis: Axis) -> Matrix<T> {
+    collapse(
+        axis,
+        lanes(&a.as_view(), axis).map(|lane| prod(&lane)).collect(),
+    )
+}
+
+pub fn mean<T: Scalar>(a: &impl AsView<T>) -> Result<T, Error> {
+    let a = a.as_view();
+    let n = check_len(&a, 1, "mean")?;
+    Ok(sum(&a) / T::from_f64(n as f64))
+}
+
+pub fn mean_axis<T: Scalar>(a: &impl AsView<T>, axis: Axis) -> Result<Matrix<T>, Error> {
+    let means = lanes(&a.as_view(), axis).map(|lane| mean(&lane));
+    Ok(collapse(axis, means.collect::<Result<_, _>>()?))
+}
+
+fn variance<T: RealField>(a: &MatrixView<T>, ddof: usize, op: &'static str) -> Result<T, Error> {
+    // no matrix holds usize::MAX + 1 elements, so report it as a bad ddof
+    let needed = ddof.checked_add(1).ok_or(Error::InvalidArgument {
+        op,
+        reason: "ddof is too large",
+    })?;
+    let n = check_len(a, needed, op)?;
+    // two passes: subtracting the mean first avoids the cancellation of
+    // E[x^2] - E[x]^2
+    let mean = sum(a) / T::from_usize(n);
+    let squares: T = a.iter().map(|x| (x - mean) * (x - mean)).sum();
+    Ok(squares / T::from_usize(n - ddof))
+}
+
+// sum of squared deviations divided by n - ddof: ddof = 0 for the population
+// variance, 1 for the unbiased sample variance
+pub fn var<T: RealField>(a: &impl AsView<T>, ddof: usize) -> Result<T, Error> {
+    variance(&a.as_view(), ddof, "var")
+}
+
+pub fn var_axis<T: RealField>(
+    a: &impl AsView<T>,
+    axis: Axis,
+    ddof: usize,
+) -> Result<Matrix<T>, Error> {
+    let vars = lanes(&a.as_view(), axis).map(|lane| variance(&lane, ddof, "var"));
+    Ok(collapse(axis, vars.collect::<Result<_, _>>()?))
+}
+
+pub fn std<T: RealField>(a: &impl AsView<T>, ddof: usize) -> Result<T, Error> {
+    Ok(variance(&a.as_view(), ddof, "std")?.sqrt())
+}
+
+pub fn std_axis<T: RealField>(
+    a: &impl AsView<T>,
+    axis: Axis,
+    ddof: usize,
+) -> Result<Matrix<T>, Error> {
+    let stds = lanes(&a.as_view(), axis).map(|lane| Ok(variance(&lane, ddof, "std")?.sqrt()));
+    Ok(collapse(axis, stds.collect::<Result<_, _>>()?))
+}
+
+// position of the first element that `better` prefers to all earlier ones;
+// a NaN wins outright so it propagates to min and max like in NumPy
+fn extreme<T: RealField>(
+    a: &MatrixView<T>,
+    op: &'static str,
+    better: impl Fn(T, T) -> bool,
+) -> Result<(usize, usize), Error> {
+    let n = check_len(a, 1, op)?;
+    let mut best = 0;
+    for k in 0..n {
+        let x = a.flat(k);
+        if x.is_nan() {
+            best = k;
+            break;
+        }
+        if better(x, a.flat(best)) {
+            best = k;
+        }
+    }
+    Ok((best / a.cols, best % a.cols))
+}
+
+// (row, col) of the smallest element, the first one on ties
+pub fn argmin<T: RealField>(a: &impl AsView<T>) -> Result<(usize, usize), Error> {
+    extreme(&a.as_view(), "argmin", |x, best| x < best)
+}
+
+pub fn argmax<T: RealField>(a: &impl AsView<T>) -> Result<(usize, usize), Error> {
+    extreme(&a.as_view(), "argmax", |x, best| x > best)
+}
+
+// index within each lane: the row of each column's minimum for Axis::Rows,
+// the column of each row's minimum for Axis::Cols
+pub fn argmin_axis<T: RealField>(a: &impl AsView<T>, axis: Axis) -> Result<Vec<usize>, Error> {
+    lanes(&a.as_view(), axis)
+        .map(|lane| Ok(lane_index(argmin(&lane)?, axis)))
+        .collect()
+}
+
+pub fn argmax_axis<T: RealField>(a: &impl AsView<T>, axis: Axis) -> Result<Vec<usize>, Error> {
+    lanes(&a.as_view(), axis)
+        .map(|lane| Ok(lane_index(argmax(&lane)?, axis)))
+        .collect()
+}
+
+fn lane_index((row, col): (usize, usize), axis: Axis) -> usize {
+    match axis {
+        Axis::Rows => row,
+        Axis::Cols => col,
+    }
+}
+
+pub fn min<T: RealField>(a: &impl AsView<T>) -> Result<T, Error> {
+    let a = a.as_view();
+    let (i, j) = extreme(&a, "min", |x, best| x < best)?;
+    Ok(a.at(i, j))
+}
+
+pub fn max<T: RealField>(a: &impl AsView<T>) -> Result<T, Error> {
+    let a = a.as_view();
+    let (i, j) = extreme(&a, "max", |x, best| x > best)?;
+    Ok(a.at(i, j))
+}
+
+pub fn min_axis<T: RealField>(a: &impl AsView<T>, axis: Axis) -> Result<Matrix<T>, Error> {
+    let mins = lanes(&a.as_view(), axis).map(|lane| min(&lane));
+    Ok(collapse(axis, mins.collect::<Result<_, _>>()?))
+}
+
+pub fn max_axis<T: RealField>(a: &impl AsView<T>, axis: Axis) -> Result<Matrix<T>, Error> {
+    let maxs = lanes(&a.as_view(), axis).map(|lane| max(&lane));
+    Ok(collapse(axis, maxs.collect::<Result<_, _>>()?))
+}
+
+// running sum in row-major order, same shape as `a`
+pub fn cumsum<T: Scalar>(a: &impl AsView<T>) -> Matrix<T> {
+    let mut out = a.as_view().to_matrix();
+    for k in 1..out.data.len() {
+        let previous = out.data[k - 1];
+        out.data[k] += previous;
+    }
+    out
+}
+
+// running sums down each column (Axis::Rows) or along each row (Axis::Cols)
+pub fn cumsum_axis<T: Scalar>(a: &impl AsView<T>, axis: Axis) -> Matrix<T> {
+    let mut out = a.as_view().to_matrix();
+    let (rows, cols) = out.shape();
+    for i in 0..rows {
+        for j in 0..cols {
+            let previous = match axis {
+                Axis::Rows if i > 0 => out.get(i - 1, j),
+                Axis::Cols if j > 0 => out.get(i, j - 1),
+                _ => continue,
+            };
+            out.data[i * cols + j] += previous;
+        }
+    }
+    out
+}
+
+// sample covariance of the columns, with each row one observation: a
+// cols x cols matrix normalized by n - 1
+pub fn covariance<T: RealField>(a: &impl AsView<T>) -> Result<Matrix<T>, Error> {
+    let a = a.as_view();
+    let n = a.rows;
+    if n < 2 {
+        return Err(Error::TooFewElements {
+            op: "covariance",
+            needed: 2,
+            found: n,
+        });
+    }
+
+    let centered = ops::sub(&a, &mean_axis(&a, Axis::Rows)?)?;
+    let mut cov = Matrix::zeros(a.cols, a.cols);
+    let scale = T::one() / T::from_usize(n - 1);
+    gemm(
+        scale,
+        &centered,
+        Transpose::Yes,
+        &centered,
+        Transpose::No,
+        T::zero(),
+        &mut cov,
+    )?;
+
+    // the blocked product need not round both triangles identically
+    for i in 0..a.cols {
+        for j in (i + 1)..a.cols {
+            cov.set(j, i, cov.get(i, j));
+        }
+    }
+    Ok(cov)
+}
+
+// Pearson correlation of the columns; entries involving a constant column are
+// NaN, as in NumPy
+pub fn correlation<T: RealField>(a: &impl AsView<T>) -> Result<Matrix<T>, Error> {
+    let mut corr = covariance(a)?;
+    let d: Vec<T> = (0..corr.rows).map(|i| corr.get(i, i).sqrt()).collect();
+    for i in 0..corr.rows {
+        for j in 0..corr.cols {
+            let r = corr.get(i, j) / (d[i] * d[j]);
+            // clamp rounding excursions past +/-1, leaving NaN alone
+            let r = if r > T::one() {
+                T::one()
+            } else if r < -T::one() {
+                -T::one()
+            } else {
+                r
+            };
+            corr.set(i, j, r);
+        }
+        if d[i] > T::zero() {
+            corr.set(i, i, T::one());
+        }
+    }
+    Ok(corr)
+}
@@ -0,0 +1,477 @@
+use crate::matrix::matrix::Matrix;
+use crate::utils::error::Error;
+use crate::utils::scalar::Scalar;
+
+// coordinate (triplet) format, the cheap way to assemble a sparse matrix;
+// duplicate entries are summed when converting
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T = f64> {
+    pub rows: usize,
+    pub cols: usize,
+    pub row_indices: Vec<usize>,
+    pub col_indices: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+// compressed sparse row: the entries of row i are
+// values[row_offsets[i]..row_offsets[i + 1]], sorted by column without duplicates
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T = f64> {
+    pub rows: usize,
+    pub cols: usize,
+    pub row_offsets: Vec<usize>,
+    pub col_indices: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+// compressed sparse column, the column-wise mirror of CsrMatrix
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T = f64> {
+    pub rows: usize,
+    pub cols: usize,
+    pub col_offsets: Vec<usize>,
+    pub row_indices: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+impl<T: Scalar> CooMatrix<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            row_indices: Vec::new(),
+            col_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: usize, col: usize, value: T) -> Result<(), Error> {
+        if row >= self.rows || col >= self.cols {
+            return Err(Error::IndexOutOfBounds {
+                index: (row, col),
+                shape: (self.rows, self.cols),
+            });
+        }
+        self.push_entry(row, col, value);
+        Ok(())
+    }
+
+    // push for indices known to be in range
+    fn push_entry(&mut self, row: usize, col: usize, value: T) {
+        self.row_indices.push(row);
+        self.col_indices.push(col);
+        self.values.push(value);
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn from_dense(matrix: &Matrix<T>) -> Self {
+        let mut coo = Self::new(matrix.rows, matrix.cols);
+        for i in 0..matrix.rows {
+            for j in 0..matrix.cols {
+                let value = matrix.get(i, j);
+                if value != T::zero() {
+                    coo.push_entry(i, j, value);
+                }
+            }
+        }
+        coo
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut dense = Matrix::zeros(self.rows, self.cols);
+        for k in 0..self.nnz() {
+            dense.data[self.row_indices[k] * self.cols + self.col_indices[k]] += self.values[k];
+        }
+        dense
+    }
+
+    pub fn transpose(&self) -> CooMatrix<T> {
+        CooMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_indices: self.col_indices.clone(),
+            col_indices: self.row_indices.clone(),
+            values: self.values.clone(),
+        }
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let mut order: Vec<usize> = (0..self.nnz()).collect();
+        order.sort_by_key(|&k| (self.row_indices[k], self.col_indices[k]));
+
+        let mut row_offsets = vec![0; self.rows + 1];
+        let mut col_indices = Vec::with_capacity(order.len());
+        let mut values: Vec<T> = Vec::with_capacity(order.len());
+        let mut last = None;
+        for k in order {
+            let entry = (self.row_indices[k], self.col_indices[k]);
+            if last == Some(entry) {
+                *values.last_mut().expect("entry was pushed") += self.values[k];
+                continue;
+            }
+            row_offsets[entry.0 + 1] += 1;
+            col_indices.push(entry.1);
+            values.push(self.values[k]);
+            last = Some(entry);
+        }
+        for i in 0..self.rows {
+            row_offsets[i + 1] += row_offsets[i];
+        }
+
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        // the CSR arrays of A^T are the CSC arrays of A
+        let t = self.transpose().to_csr();
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            col_offsets: t.row_offsets,
+            row_indices: t.col_indices,
+            values: t.values,
+        }
+    }
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn from_dense(matrix: &Matrix<T>) -> Self {
+        CooMatrix::from_dense(matrix).to_csr()
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut dense = Matrix::zeros(self.rows, self.cols);
+        for i in 0..self.rows {
+            for k in self.row_offsets[i]..self.row_offsets[i + 1] {
+                dense.set(i, self.col_indices[k], self.values[k]);
+            }
+        }
+        dense
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        let mut coo = CooMatrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for k in self.row_offsets[i]..self.row_offsets[i + 1] {
+                coo.push_entry(i, self.col_indices[k], self.values[k]);
+            }
+        }
+        coo
+    }
+
+    // counting sort over columns, O(nnz + cols)
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let mut col_offsets = vec![0; self.cols + 1];
+        for &j in &self.col_indices {
+            col_offsets[j + 1] += 1;
+        }
+        for j in 0..self.cols {
+            col_offsets[j + 1] += col_offsets[j];
+        }
+
+        let mut next = col_offsets.clone();
+        let mut row_indices = vec![0; self.nnz()];
+        let mut values = vec![T::zero(); self.nnz()];
+        for i in 0..self.rows {
+            for k in self.row_offsets[i]..self.row_offsets[i + 1] {
+                let j = self.col_indices[k];
+                row_indices[next[j]] = i;
+                values[next[j]] = self.values[k];
+                next[j] += 1;
+            }
+        }
+
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        let csc = self.to_csc();
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets: csc.col_offsets,
+            col_indices: csc.row_indices,
+            values: csc.values,
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        match self.col_indices[range.clone()].binary_search(&col) {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    // sparse * dense
+    pub fn mul_dense(&self, other: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        if self.cols != other.rows {
+            return Err(Error::DimensionMismatch {
+                op: "sparse mul_dense",
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+
+        let n = other.cols;
+        let mut result = Matrix::zeros(self.rows, n);
+        for i in 0..self.rows {
+            for k in self.row_offsets[i]..self.row_offsets[i + 1] {
+                let (j, value) = (self.col_indices[k], self.values[k]);
+                for c in 0..n {
+                    result.data[i * n + c] += value * other.get(j, c);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    // sparse * sparse (Gustavson), one dense accumulator row reused throughout
+    pub fn mul(&self, other: &CsrMatrix<T>) -> Result<CsrMatrix<T>, Error> {
+        if self.cols != other.rows {
+            return Err(Error::DimensionMismatch {
+                op: "sparse mul",
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+
+        let mut accumulator = vec![T::zero(); other.cols];
+        let mut seen = vec![usize::MAX; other.cols];
+        let mut pattern = Vec::new();
+        let mut row_offsets = Vec::with_capacity(self.rows + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        row_offsets.push(0);
+
+        for i in 0..self.rows {
+            pattern.clear();
+            for k in self.row_offsets[i]..self.row_offsets[i + 1] {
+                let (j, value) = (self.col_indices[k], self.values[k]);
+                for kk in other.row_offsets[j]..other.row_offsets[j + 1] {
+                    let c = other.col_indices[kk];
+                    if seen[c] != i {
+                        seen[c] = i;
+                        accumulator[c] = T::zero();
+                        pattern.push(c);
+                    }
+                    accumulator[c] += value * other.values[kk];
+                }
+            }
+            pattern.sort_unstable();
+            for &c in &pattern {
+                col_indices.push(c);
+                values.push(accumulator[c]);
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        Ok(CsrMatrix {
+            rows: self.rows,
+            cols: other.cols,
+            row_offsets,
+            col_indices,
+            values,
+        })
+    }
+
+    // merges the sorted rows of both operands
+    pub fn add(&self, other: &CsrMatrix<T>) -> Result<CsrMatrix<T>, Error> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(Error::DimensionMismatch {
+                op: "sparse add",
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+
+        let mut row_offsets = Vec::with_capacity(self.rows + 1);
+        let mut col_indices = Vec::with_capacity(self.nnz() + other.nnz());
+        let mut values = Vec::with_capacity(self.nnz() + other.nnz());
+        row_offsets.push(0);
+
+        for i in 0..self.rows {
+            let (mut a, a_end) = (self.row_offsets[i], self.row_offsets[i + 1]);
+            let (mut b, b_end) = (other.row_offsets[i], other.row_offsets[i + 1]);
+            while a < a_end || b < b_end {
+                let a_col = if a < a_end {
+                    self.col_indices[a]
+                } else {
+                    usize::MAX
+                };
+                let b_col = if b < b_end {
+                    other.col_indices[b]
+                } else {
+                    usize::MAX
+                };
+                if a_col == b_col {
+                    col_indices.push(a_col);
+                    values.push(self.values[a] + other.values[b]);
+                    a += 1;
+                    b += 1;
+                } else if a_col < b_col {
+                    col_indices.push(a_col);
+                    values.push(self.values[a]);
+                    a += 1;
+                } else {
+                    col_indices.push(b_col);
+                    values.push(other.values[b]);
+                    b += 1;
+                }
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        Ok(CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values,
+        })
+    }
+
+    // reinterprets the CSR arrays of A^T as the CSC arrays of A
+    fn transpose_into_csc(self) -> CscMatrix<T> {
+        CscMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            col_offsets: self.row_offsets,
+            row_indices: self.col_indices,
+            values: self.values,
+        }
+    }
+}
+
+impl<T: Scalar> CscMatrix<T> {
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn from_dense(matrix: &Matrix<T>) -> Self {
+        CooMatrix::from_dense(matrix).to_csc()
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut dense = Matrix::zeros(self.rows, self.cols);
+        for j in 0..self.cols {
+            for k in self.col_offsets[j]..self.col_offsets[j + 1] {
+                dense.set(self.row_indices[k], j, self.values[k]);
+            }
+        }
+        dense
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        // read as CSR these arrays hold A^T, whose CSC arrays are the CSR of A
+        let csc = self.transposed_csr().to_csc();
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets: csc.col_offsets,
+            col_indices: csc.row_indices,
+            values: csc.values,
+        }
+    }
+
+    pub fn transpose(&self) -> CscMatrix<T> {
+        let csr = self.to_csr();
+        CscMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            col_offsets: csr.row_offsets,
+            row_indices: csr.col_indices,
+            values: csr.values,
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        let range = self.col_offsets[col]..self.col_offsets[col + 1];
+        match self.row_indices[range.clone()].binary_search(&row) {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    // sparse * dense, scattering column j of A against row j of B
+    pub fn mul_dense(&self, other: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        if self.cols != other.rows {
+            return Err(Error::DimensionMismatch {
+                op: "sparse mul_dense",
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+
+        let n = other.cols;
+        let mut result = Matrix::zeros(self.rows, n);
+        for j in 0..self.cols {
+            for k in self.col_offsets[j]..self.col_offsets[j + 1] {
+                let (i, value) = (self.row_indices[k], self.values[k]);
+                for c in 0..n {
+                    result.data[i * n + c] += value * other.get(j, c);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn mul(&self, other: &CscMatrix<T>) -> Result<CscMatrix<T>, Error> {
+        // checked here so the error reports A and B rather than their transposes
+        if self.cols != other.rows {
+            return Err(Error::DimensionMismatch {
+                op: "sparse mul",
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+        // (A * B)^T = B^T * A^T, and the CSC arrays of A are the CSR arrays of A^T
+        Ok(other
+            .transposed_csr()
+            .mul(&self.transposed_csr())?
+            .transpose_into_csc())
+    }
+
+    pub fn add(&self, other: &CscMatrix<T>) -> Result<CscMatrix<T>, Error> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(Error::DimensionMismatch {
+                op: "sparse add",
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+        Ok(self
+            .transposed_csr()
+            .add(&other.transposed_csr())?
+            .transpose_into_csc())
+    }
+
+    fn transposed_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets: self.col_offsets.clone(),
+            col_indices: self.row_indices.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
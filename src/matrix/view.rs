@@ -0,0 +1,461 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::matrix::matrix::{Layout, Matrix};
+use crate::utils::error::Error;
+use crate::utils::scalar::Scalar;
+
+// Borrowed, strided windows into a matrix buffer. Element (i, j) of a view lives
+// at data[i * row_stride + j * col_stride], so rows, columns, diagonals,
+// rectangular blocks and transposes of a matrix are all views of the same
+// buffer and none of them copy.
+
+#[derive(Debug)]
+pub struct MatrixView<'a, T = f64> {
+    pub rows: usize,
+    pub cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+    data: &'a [T],
+}
+
+// shared views are Copy whatever T is, like the slice they borrow
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T = f64> {
+    pub rows: usize,
+    pub cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+    data: &'a mut [T],
+}
+
+// anything that can be read as a matrix without copying; `ops` takes its
+// operands through this, so a Matrix and any view of one can be mixed freely
+pub trait AsView<T> {
+    fn as_view(&self) -> MatrixView<'_, T>;
+}
+
+// elements a rows x cols layout spans in its buffer
+fn span(rows: usize, cols: usize, row_stride: usize, col_stride: usize) -> usize {
+    if rows == 0 || cols == 0 {
+        0
+    } else {
+        (rows - 1) * row_stride + (cols - 1) * col_stride + 1
+    }
+}
+
+fn check_span(len: usize, rows: usize, cols: usize, row_stride: usize, col_stride: usize) {
+    assert!(
+        span(rows, cols, row_stride, col_stride) <= len,
+        "a {}x{} view with strides ({}, {}) overruns a buffer of {} elements",
+        rows,
+        cols,
+        row_stride,
+        col_stride,
+        len
+    );
+}
+
+fn check_index(shape: (usize, usize), row: usize, col: usize) {
+    assert!(
+        row < shape.0 && col < shape.1,
+        "index ({}, {}) out of bounds for a {}x{} view",
+        row,
+        col,
+        shape.0,
+        shape.1
+    );
+}
+
+// buffer offset and shape of the block rows x cols within a view
+fn block(
+    shape: (usize, usize),
+    strides: (usize, usize),
+    rows: &Range<usize>,
+    cols: &Range<usize>,
+) -> (usize, usize, usize) {
+    assert!(
+        rows.start <= rows.end
+            && rows.end <= shape.0
+            && cols.start <= cols.end
+            && cols.end <= shape.1,
+        "slice {:?} x {:?} out of bounds for a {}x{} view",
+        rows,
+        cols,
+        shape.0,
+        shape.1
+    );
+    let offset = rows.start * strides.0 + cols.start * strides.1;
+    (offset, rows.len(), cols.len())
+}
+
+// true when the elements are stored row by row with no gaps
+fn is_contiguous(rows: usize, cols: usize, row_stride: usize, col_stride: usize) -> bool {
+    (cols <= 1 || col_stride == 1) && (rows <= 1 || row_stride == cols)
+}
+
+// the gap-free storage order of a layout, if it has one; vectors and scalars
+// count as row-major
+fn storage_layout(
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+) -> Option<Layout> {
+    if is_contiguous(rows, cols, row_stride, col_stride) {
+        Some(Layout::RowMajor)
+    } else if is_contiguous(cols, rows, col_stride, row_stride) {
+        Some(Layout::ColMajor)
+    } else {
+        None
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn new(
+        data: &'a [T],
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Self {
+        check_span(data.len(), rows, cols, row_stride, col_stride);
+        Self {
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+            data,
+        }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn strides(&self) -> (usize, usize) {
+        (self.row_stride, self.col_stride)
+    }
+
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'a, T> {
+        let (offset, rows, cols) = block(self.shape(), self.strides(), &rows, &cols);
+        MatrixView {
+            rows,
+            cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            data: &self.data[offset.min(self.data.len())..],
+        }
+    }
+
+    // 1 x cols
+    pub fn row(&self, i: usize) -> MatrixView<'a, T> {
+        self.slice(i..i + 1, 0..self.cols)
+    }
+
+    // rows x 1
+    pub fn col(&self, j: usize) -> MatrixView<'a, T> {
+        self.slice(0..self.rows, j..j + 1)
+    }
+
+    // the main diagonal as a column vector
+    pub fn diag(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            rows: self.rows.min(self.cols),
+            cols: 1,
+            row_stride: self.row_stride + self.col_stride,
+            col_stride: self.col_stride,
+            data: self.data,
+        }
+    }
+
+    // transpose, swapping the strides
+    pub fn t(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            data: self.data,
+        }
+    }
+
+    // the underlying elements and their order, when they are stored without
+    // gaps, so kernels can walk memory in order whatever the layout
+    pub fn storage(&self) -> Option<(Layout, &'a [T])> {
+        storage_layout(self.rows, self.cols, self.row_stride, self.col_stride)
+            .map(|layout| (layout, &self.data[..self.rows * self.cols]))
+    }
+
+    // the elements in row-major order, when that is how they are stored
+    pub fn as_slice(&self) -> Option<&'a [T]> {
+        if is_contiguous(self.rows, self.cols, self.row_stride, self.col_stride) {
+            Some(&self.data[..self.rows * self.cols])
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: Copy> MatrixView<'a, T> {
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self[(row, col)]
+    }
+
+    // element (row, col) without the shape check, for hot loops whose indices
+    // are in range by construction
+    pub(crate) fn at(&self, row: usize, col: usize) -> T {
+        self.data[row * self.row_stride + col * self.col_stride]
+    }
+
+    // element k in row-major order, unchecked beyond the buffer bounds
+    pub(crate) fn flat(&self, k: usize) -> T {
+        self.data[(k / self.cols) * self.row_stride + (k % self.cols) * self.col_stride]
+    }
+
+    // elements in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+        let view = *self;
+        (0..self.rows * self.cols).map(move |k| view.flat(k))
+    }
+}
+
+impl<T: Scalar> MatrixView<'_, T> {
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let data = match self.as_slice() {
+            Some(data) => data.to_vec(),
+            None => self.iter().collect(),
+        };
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    pub fn new(
+        data: &'a mut [T],
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Self {
+        check_span(data.len(), rows, cols, row_stride, col_stride);
+        Self {
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+            data,
+        }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn strides(&self) -> (usize, usize) {
+        (self.row_stride, self.col_stride)
+    }
+
+    // read-only reborrow
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            data: self.data,
+        }
+    }
+
+    pub fn slice_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, T> {
+        self.reborrow().into_slice(rows, cols)
+    }
+
+    fn reborrow(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            data: self.data,
+        }
+    }
+
+    // slice_mut that keeps the original borrow
+    pub fn into_slice(self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'a, T> {
+        let (offset, rows, cols) = block(self.shape(), self.strides(), &rows, &cols);
+        let start = offset.min(self.data.len());
+        MatrixViewMut {
+            rows,
+            cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            data: &mut self.data[start..],
+        }
+    }
+
+    pub fn into_diag(self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            rows: self.rows.min(self.cols),
+            cols: 1,
+            row_stride: self.row_stride + self.col_stride,
+            col_stride: self.col_stride,
+            data: self.data,
+        }
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T> {
+        let cols = self.cols;
+        self.slice_mut(i..i + 1, 0..cols)
+    }
+
+    pub fn col_mut(&mut self, j: usize) -> MatrixViewMut<'_, T> {
+        let rows = self.rows;
+        self.slice_mut(0..rows, j..j + 1)
+    }
+
+    pub fn diag_mut(&mut self) -> MatrixViewMut<'_, T> {
+        self.reborrow().into_diag()
+    }
+
+    pub fn as_slice_mut(&mut self) -> Option<&mut [T]> {
+        if is_contiguous(self.rows, self.cols, self.row_stride, self.col_stride) {
+            Some(&mut self.data[..self.rows * self.cols])
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Copy> MatrixViewMut<'_, T> {
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self[(row, col)]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self[(row, col)] = value;
+    }
+
+    pub fn fill(&mut self, value: T) {
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self.data[i * self.row_stride + j * self.col_stride] = value;
+            }
+        }
+    }
+
+    // overwrites the view with `source`, which must have the same shape
+    pub fn copy_from(&mut self, source: &impl AsView<T>) -> Result<(), Error> {
+        let source = source.as_view();
+        if source.shape() != self.shape() {
+            return Err(Error::DimensionMismatch {
+                op: "copy_from",
+                left: self.shape(),
+                right: source.shape(),
+            });
+        }
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self.data[i * self.row_stride + j * self.col_stride] = source.get(i, j);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        check_index(self.shape(), row, col);
+        &self.data[row * self.row_stride + col * self.col_stride]
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        check_index(self.shape(), row, col);
+        &self.data[row * self.row_stride + col * self.col_stride]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        check_index(self.shape(), row, col);
+        &mut self.data[row * self.row_stride + col * self.col_stride]
+    }
+}
+
+impl<T> AsView<T> for Matrix<T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        self.view()
+    }
+}
+
+impl<T> AsView<T> for MatrixView<'_, T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        *self
+    }
+}
+
+impl<T> AsView<T> for MatrixViewMut<'_, T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        self.view()
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn view(&self) -> MatrixView<'_, T> {
+        let (row_stride, col_stride) = self.strides();
+        MatrixView::new(&self.data, self.rows, self.cols, row_stride, col_stride)
+    }
+
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (rows, cols) = self.shape();
+        let (row_stride, col_stride) = self.strides();
+        MatrixViewMut::new(&mut self.data, rows, cols, row_stride, col_stride)
+    }
+
+    pub fn row(&self, i: usize) -> MatrixView<'_, T> {
+        self.view().row(i)
+    }
+
+    pub fn col(&self, j: usize) -> MatrixView<'_, T> {
+        self.view().col(j)
+    }
+
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'_, T> {
+        self.view().slice(rows, cols)
+    }
+
+    pub fn diag(&self) -> MatrixView<'_, T> {
+        self.view().diag()
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T> {
+        let cols = self.cols;
+        self.slice_mut(i..i + 1, 0..cols)
+    }
+
+    pub fn col_mut(&mut self, j: usize) -> MatrixViewMut<'_, T> {
+        let rows = self.rows;
+        self.slice_mut(0..rows, j..j + 1)
+    }
+
+    pub fn slice_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, T> {
+        self.view_mut().into_slice(rows, cols)
+    }
+
+    pub fn diag_mut(&mut self) -> MatrixViewMut<'_, T> {
+        self.view_mut().into_diag()
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::utils::scalar::{RealField, Scalar};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T = f64> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: RealField> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+
+    pub fn conj(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    pub fn abs(&self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    pub fn arg(&self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    pub fn is_real(&self) -> bool {
+        self.im == T::zero()
+    }
+
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+}
+
+impl<T: RealField> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Self::new(re, T::zero())
+    }
+}
+
+impl<T: RealField> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: RealField> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: RealField> Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+// Smith's algorithm, avoids overflow in the intermediate |rhs|^2
+impl<T: RealField> Div for Complex<T> {
+    type Output = Complex<T>;
+
+    fn div(self, rhs: Complex<T>) -> Complex<T> {
+        if rhs.re.abs() > rhs.im.abs() {
+            let r = rhs.im / rhs.re;
+            let d = rhs.re + r * rhs.im;
+            Complex::new((self.re + r * self.im) / d, (self.im - r * self.re) / d)
+        } else {
+            let r = rhs.re / rhs.im;
+            let d = rhs.im + r * rhs.re;
+            Complex::new((r * self.re + self.im) / d, (r * self.im - self.re) / d)
+        }
+    }
+}
+
+impl<T: RealField> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T: RealField> AddAssign for Complex<T> {
+    fn add_assign(&mut self, rhs: Complex<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: RealField> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, rhs: Complex<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: RealField> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, rhs: Complex<T>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: RealField> DivAssign for Complex<T> {
+    fn div_assign(&mut self, rhs: Complex<T>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: RealField> Sum for Complex<T> {
+    fn sum<I: Iterator<Item = Complex<T>>>(iter: I) -> Self {
+        iter.fold(Complex::new(T::zero(), T::zero()), |acc, x| acc + x)
+    }
+}
+
+impl<T: RealField> Scalar for Complex<T> {
+    type Real = T;
+
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+    fn from_real(value: T) -> Self {
+        Complex::from(value)
+    }
+    fn from_f64(value: f64) -> Self {
+        Complex::from(T::from_f64(value))
+    }
+    fn real(self) -> T {
+        self.re
+    }
+    fn imag(self) -> T {
+        self.im
+    }
+    fn conj(self) -> Self {
+        Complex::conj(&self)
+    }
+    fn abs(self) -> T {
+        Complex::abs(&self)
+    }
+
+    // principal square root
+    fn sqrt(self) -> Self {
+        let r = Complex::abs(&self);
+        let half = T::from_f64(0.5);
+        let re = ((r + self.re) * half).sqrt();
+        let im = ((r - self.re) * half).sqrt();
+        Complex::new(re, if self.im < T::zero() { -im } else { im })
+    }
+
+    fn powf(self, exponent: T) -> Self {
+        if self == Self::zero() {
+            return self;
+        }
+        Complex::from_polar(Complex::abs(&self).powf(exponent), self.arg() * exponent)
+    }
+}
+
+impl<T: RealField> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < T::zero() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
@@ -1,22 +1,159 @@
 use std::error::Error as StdError;
 use std::fmt;
 
-#[derive(Debug)]
+// Every variant records what is needed to log the failure without the inputs:
+// `op` names the failing operation, shapes are (rows, cols) and indices are
+// zero based. Tolerances and condition numbers are widened to f64 so the type
+// stays independent of the matrix scalar.
+#[derive(Debug, Clone, PartialEq)]
 pub enum Error {
-    MatrixSizeMismatch,
-    MatrixNotSquare,
-    SingularMatrix,
+    // the operands of `op` have incompatible shapes
+    DimensionMismatch {
+        op: &'static str,
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    MatrixNotSquare {
+        op: &'static str,
+        shape: (usize, usize),
+    },
+    // `op` needs a 1xN or Nx1 matrix
+    NotAVector {
+        op: &'static str,
+        shape: (usize, usize),
+    },
+    // `op` needs a vector of exactly `expected` elements
+    VectorLength {
+        op: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    // `op` needs a vector that is not all zeros
+    ZeroVector {
+        op: &'static str,
+    },
+    // (row, col) and (col, row) differ by more than `tolerance`, relative to
+    // their magnitude
+    MatrixNotSymmetric {
+        row: usize,
+        col: usize,
+        tolerance: f64,
+    },
+    // the factorization met a zero pivot in column `pivot`, exactly zero for LU
+    // and below the relative rank tolerance for QR and triangular solves
+    SingularMatrix {
+        pivot: usize,
+    },
+    // the estimated reciprocal condition number fell below `tolerance`
+    IllConditioned {
+        rcond: f64,
+        tolerance: f64,
+    },
+    // the Cholesky pivot in column `pivot` was not positive
+    NotPositiveDefinite {
+        pivot: usize,
+    },
+    // `op` stopped after `iters` iterations without converging
+    NotConverged {
+        op: &'static str,
+        iters: usize,
+    },
+    IndexOutOfBounds {
+        index: (usize, usize),
+        shape: (usize, usize),
+    },
+    // `op` needs at least `needed` values (elements, or rows for
+    // covariance) and got `found`
+    TooFewElements {
+        op: &'static str,
+        needed: usize,
+        found: usize,
+    },
+    // a `shape` matrix was given `len` elements instead of rows * cols
+    DataLength {
+        shape: (usize, usize),
+        len: usize,
+    },
+    // a scalar argument of `op` is outside its domain, e.g. a zero step
+    InvalidArgument {
+        op: &'static str,
+        reason: &'static str,
+    },
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Error::MatrixSizeMismatch => write!(
+            Error::DimensionMismatch { op, left, right } => write!(
                 f,
-                "Matrix size mismatch: matrices must have the same dimensions"
+                "{}: dimension mismatch, {}x{} and {}x{} are not compatible",
+                op, left.0, left.1, right.0, right.1
             ),
-            Error::MatrixNotSquare => write!(f, "Matrix is not square"),
-            Error::SingularMatrix => write!(f, "Matrix is singular"),
+            Error::MatrixNotSquare { op, shape } => {
+                write!(f, "{}: matrix is not square ({}x{})", op, shape.0, shape.1)
+            }
+            Error::NotAVector { op, shape } => write!(
+                f,
+                "{}: expected a 1xN or Nx1 vector, got {}x{}",
+                op, shape.0, shape.1
+            ),
+            Error::VectorLength {
+                op,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: expected a vector of length {}, got {}",
+                op, expected, found
+            ),
+            Error::ZeroVector { op } => write!(f, "{}: vector is zero", op),
+            Error::MatrixNotSymmetric {
+                row,
+                col,
+                tolerance,
+            } => write!(
+                f,
+                "Matrix is not symmetric: ({}, {}) and ({}, {}) differ by more than {:e}",
+                row, col, col, row, tolerance
+            ),
+            Error::SingularMatrix { pivot } => {
+                write!(f, "Matrix is singular: zero pivot in column {}", pivot)
+            }
+            Error::IllConditioned { rcond, tolerance } => write!(
+                f,
+                "Matrix is too ill-conditioned to invert reliably: rcond {:e} is below {:e}",
+                rcond, tolerance
+            ),
+            Error::NotPositiveDefinite { pivot } => write!(
+                f,
+                "Matrix is not positive definite: pivot {} is not positive",
+                pivot
+            ),
+            Error::NotConverged { op, iters } => {
+                write!(f, "{}: failed to converge after {} iterations", op, iters)
+            }
+            Error::IndexOutOfBounds { index, shape } => write!(
+                f,
+                "index ({}, {}) out of bounds for a {}x{} matrix",
+                index.0, index.1, shape.0, shape.1
+            ),
+            Error::TooFewElements { op, needed, found } => {
+                write!(f, "{}: needs at least {} values, got {}", op, needed, found)
+            }
+            // the shape may be one whose element count overflows usize
+            Error::DataLength { shape, len } => match shape.0.checked_mul(shape.1) {
+                Some(needed) => write!(
+                    f,
+                    "a {}x{} matrix needs {} elements, got {}",
+                    shape.0, shape.1, needed, len
+                ),
+                None => write!(
+                    f,
+                    "a {}x{} matrix needs more than usize::MAX elements, got {}",
+                    shape.0, shape.1, len
+                ),
+            },
+            Error::InvalidArgument { op, reason } => write!(f, "{}: {}", op, reason),
         }
     }
 }
@@ -1,20 +1,18 @@
 // use crate::matrix::matrix::Matrix;
 // use crate::matrix::*;
 
-// converts a input : sparse matrix to a dense matrix
+// builds a CSR matrix from (row, col, value) triplets; like an out-of-range
+// literal index, a triplet outside the matrix panics
 #[macro_export]
 macro_rules! Sparse {
     ($rows:expr, $cols:expr, [$($row:expr, $col:expr, $val:expr),*]) => {
         {
-            let mut data = vec![0.0; $rows * $cols];
+            let mut coo = $crate::matrix::sparse::CooMatrix::new($rows, $cols);
             $(
-                data[$row * $cols + $col] = $val;
+                coo.push($row, $col, $val)
+                    .unwrap_or_else(|e| panic!("Sparse!: {}", e));
             )*
-            Matrix {
-                rows: $rows,
-                cols: $cols,
-                data,
-            }
+            coo.to_csr()
         }
     };
 }
@@ -1,2 +1,6 @@
+pub mod complex;
 pub mod error;
 pub mod macros;
+pub mod parallel;
+pub mod random;
+pub mod scalar;
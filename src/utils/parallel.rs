@@ -0,0 +1,142 @@
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+// work (roughly element updates or multiply-adds) below which a task stays on
+// the calling thread; spawning costs more than it saves
+const MIN_PARALLEL_WORK: usize = 1 << 15;
+// fixed reduction chunk used in deterministic mode, in elements
+const REDUCE_CHUNK: usize = 4096;
+
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+// caps the worker threads per operation; 0 restores the default of one thread
+// per available core. Without the `parallel` feature everything runs on the
+// calling thread regardless.
+pub fn set_num_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+pub fn num_threads() -> usize {
+    if !cfg!(feature = "parallel") {
+        return 1;
+    }
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    }
+}
+
+// in deterministic mode reductions split their input into fixed chunks and
+// combine the partial results in order, so sums are bit-for-bit identical
+// across runs and thread counts; otherwise partials are combined as threads
+// finish
+pub fn set_deterministic(enabled: bool) {
+    DETERMINISTIC.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
+
+fn threads_for(work: usize) -> usize {
+    if work < MIN_PARALLEL_WORK {
+        1
+    } else {
+        num_threads()
+    }
+}
+
+// splits `data` into contiguous runs of whole rows of `row_len` elements, one
+// run per thread, and calls f(first_row, run) on each
+pub(crate) fn for_each_rows<T, F>(data: &mut [T], row_len: usize, work: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    let rows = data.len().checked_div(row_len).unwrap_or(0);
+    let threads = threads_for(work).min(rows);
+    if threads <= 1 {
+        f(0, data);
+        return;
+    }
+
+    let per_thread = rows.div_ceil(threads);
+    let mut runs = data.chunks_mut(per_thread * row_len);
+    let first = runs.next().expect("at least one row");
+    thread::scope(|scope| {
+        for (t, run) in runs.enumerate() {
+            let f = &f;
+            scope.spawn(move || f((t + 1) * per_thread, run));
+        }
+        f(0, first);
+    });
+}
+
+// element-wise fill, out[k] = f(k)
+pub(crate) fn fill<T, F>(out: &mut [T], f: F)
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    let work = out.len();
+    for_each_rows(out, 1, work, |start, run| {
+        for (k, x) in run.iter_mut().enumerate() {
+            *x = f(start + k);
+        }
+    });
+}
+
+// reduces the index range 0..len, each index costing about `item_work`
+// elements: map(range) produces a partial result and combine merges two
+pub(crate) fn map_reduce_range<R, M, C>(len: usize, item_work: usize, map: M, combine: C) -> R
+where
+    R: Send,
+    M: Fn(Range<usize>) -> R + Sync,
+    C: Fn(R, R) -> R,
+{
+    let work = len * item_work;
+    if len == 0 {
+        return map(0..0);
+    }
+
+    if is_deterministic() {
+        let chunk_len = (REDUCE_CHUNK / item_work.max(1)).max(1);
+        let chunks = len.div_ceil(chunk_len);
+        let mut partials: Vec<Option<R>> = (0..chunks).map(|_| None).collect();
+        for_each_rows(&mut partials, 1, work, |first, slots| {
+            for (c, slot) in slots.iter_mut().enumerate() {
+                let start = (first + c) * chunk_len;
+                *slot = Some(map(start..(start + chunk_len).min(len)));
+            }
+        });
+        return partials
+            .into_iter()
+            .map(|p| p.expect("every chunk is reduced"))
+            .reduce(combine)
+            .expect("at least one chunk");
+    }
+
+    let threads = threads_for(work).min(len);
+    if threads <= 1 {
+        return map(0..len);
+    }
+    let per_thread = len.div_ceil(threads);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for start in (0..len).step_by(per_thread) {
+            let (map, sender) = (&map, sender.clone());
+            scope.spawn(move || {
+                // the receiver outlives the scope, so sending cannot fail
+                let _ = sender.send(map(start..(start + per_thread).min(len)));
+            });
+        }
+    });
+    drop(sender);
+    receiver
+        .into_iter()
+        .reduce(combine)
+        .expect("at least one partial")
+}
@@ -0,0 +1,340 @@
+use std::f64::consts::PI;
+
+use crate::matrix::decompositions::qr;
+use crate::matrix::matrix::Matrix;
+use crate::utils::error::Error;
+use crate::utils::scalar::RealField;
+
+// draws random_uniform spends on values that round up to `high`
+const UNIFORM_REDRAWS: usize = 64;
+
+// Deterministic random numbers with no external dependency. Every sampler
+// consumes a fixed number of draws per element (rejection samplers aside) in
+// row-major order, so a seed replays the same matrices bit for bit on a given
+// platform.
+
+// source of random bits for the random constructors; implement next_u64 to
+// plug in any generator, the samplers are built on top of it
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    // uniform in [0, 1), from the top 53 bits
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    // standard normal via Box-Muller; the second value of the pair is
+    // dropped so every sample uses exactly two draws
+    fn next_normal(&mut self) -> f64 {
+        // 1 - u lies in (0, 1], keeping ln away from zero
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+// Steele, Lea and Flood's SplitMix64: tiny state, passes BigCrush, and any
+// seed (including 0) gives a good stream
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+// Blackman and Vigna's xoshiro256**, the recommended generator: 256 bits of
+// state, period 2^256 - 1, and jump() for non-overlapping parallel streams
+#[derive(Debug, Clone)]
+pub struct Xoshiro256 {
+    s: [u64; 4],
+}
+
+impl Xoshiro256 {
+    // expands the seed with SplitMix64, as the authors suggest, so nearby
+    // seeds still give unrelated streams
+    pub fn new(seed: u64) -> Self {
+        let mut init = SplitMix64::new(seed);
+        Self {
+            s: [
+                init.next_u64(),
+                init.next_u64(),
+                init.next_u64(),
+                init.next_u64(),
+            ],
+        }
+    }
+
+    // stream `index` of `seed`: new(seed) advanced by index * 2^128 draws, so
+    // streams never overlap and each one replays on its own, e.g. one per
+    // worker or per experiment run
+    pub fn stream(seed: u64, index: u64) -> Self {
+        let mut rng = Self::new(seed);
+        for _ in 0..index {
+            rng.jump();
+        }
+        rng
+    }
+
+    // advances the state by 2^128 draws
+    pub fn jump(&mut self) {
+        const JUMP: [u64; 4] = [
+            0x180e_c6d3_3cfd_0aba,
+            0xd5a6_1266_f0c9_392c,
+            0xa958_2618_e03f_c9aa,
+            0x39ab_dc45_29b1_661c,
+        ];
+        let mut jumped = [0u64; 4];
+        for word in JUMP {
+            for bit in 0..64 {
+                if word & (1u64 << bit) != 0 {
+                    for (j, s) in jumped.iter_mut().zip(self.s) {
+                        *j ^= s;
+                    }
+                }
+                self.next_u64();
+            }
+        }
+        self.s = jumped;
+    }
+}
+
+impl Rng for Xoshiro256 {
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+// standard normal restricted to [low, high] (either may be infinite), by
+// Robert's rejection samplers: plain normal draws when the interval covers
+// the centre, an exponential proposal in the tails and a uniform one on
+// narrow intervals, so no case needs many retries
+fn truncated_standard_normal(low: f64, high: f64, rng: &mut impl Rng) -> f64 {
+    if high <= 0.0 {
+        return -truncated_standard_normal(-high, -low, rng);
+    }
+    if low < 0.0 {
+        if high - low >= 1.0 {
+            loop {
+                let x = rng.next_normal();
+                if low <= x && x <= high {
+                    return x;
+                }
+            }
+        }
+        // narrow interval around the centre, where exp(-x^2 / 2) <= 1
+        loop {
+            let x = low + (high - low) * rng.next_f64();
+            if rng.next_f64() <= (-0.5 * x * x).exp() {
+                return x;
+            }
+        }
+    }
+
+    // low >= 0: the density is largest at low
+    let rate = 0.5 * (low + (low * low + 4.0).sqrt());
+    if high - low < 1.0 / rate {
+        loop {
+            let x = low + (high - low) * rng.next_f64();
+            if rng.next_f64() <= (0.5 * (low * low - x * x)).exp() {
+                return x;
+            }
+        }
+    }
+    loop {
+        let x = low - (1.0 - rng.next_f64()).ln() / rate;
+        if x <= high && rng.next_f64() <= (-0.5 * (x - rate) * (x - rate)).exp() {
+            return x;
+        }
+    }
+}
+
+// random constructors; elements are drawn in row-major order so a seed gives
+// the same matrix for f32 and f64 up to rounding
+impl<T: RealField> Matrix<T> {
+    // uniform in [low, high), or all low when low == high
+    pub fn random_uniform(
+        rows: usize,
+        cols: usize,
+        low: T,
+        high: T,
+        rng: &mut impl Rng,
+    ) -> Result<Self, Error> {
+        if !(low.is_finite() && high.is_finite()) {
+            return Err(Error::InvalidArgument {
+                op: "random_uniform",
+                reason: "low and high must be finite",
+            });
+        }
+        if low > high {
+            return Err(Error::InvalidArgument {
+                op: "random_uniform",
+                reason: "low must not exceed high",
+            });
+        }
+        let (lo, hi) = (low.to_f64(), high.to_f64());
+        if !(hi - lo).is_finite() {
+            return Err(Error::InvalidArgument {
+                op: "random_uniform",
+                reason: "high - low must be finite",
+            });
+        }
+        Ok(Matrix::from_fn(rows, cols, |_, _| {
+            // rounding, e.g. narrowing to f32, can land exactly on high; the
+            // redraws are bounded so adjacent bounds cannot stall, and low is
+            // always a valid fallback
+            for _ in 0..UNIFORM_REDRAWS {
+                let x = T::from_f64(lo + (hi - lo) * rng.next_f64());
+                if x < high {
+                    return x;
+                }
+            }
+            low
+        }))
+    }
+
+    pub fn random_normal(rows: usize, cols: usize, mean: T, std: T, rng: &mut impl Rng) -> Self {
+        let (mean, std) = (mean.to_f64(), std.to_f64());
+        Matrix::from_fn(rows, cols, |_, _| {
+            T::from_f64(mean + std * rng.next_normal())
+        })
+    }
+
+    // N(mean, std^2) conditioned on [low, high], e.g. mean -/+ 2 std for the
+    // usual truncated-normal weight init
+    pub fn random_truncated_normal(
+        rows: usize,
+        cols: usize,
+        mean: T,
+        std: T,
+        low: T,
+        high: T,
+        rng: &mut impl Rng,
+    ) -> Result<Self, Error> {
+        if !(mean.is_finite() && std.is_finite() && std > T::zero()) {
+            return Err(Error::InvalidArgument {
+                op: "random_truncated_normal",
+                reason: "mean must be finite and std finite and positive",
+            });
+        }
+        // infinite bounds give a one-sided or untruncated normal
+        if low.is_nan() || high.is_nan() || low >= high {
+            return Err(Error::InvalidArgument {
+                op: "random_truncated_normal",
+                reason: "low must be below high and neither may be NaN",
+            });
+        }
+        let (mean, std) = (mean.to_f64(), std.to_f64());
+        let low = (low.to_f64() - mean) / std;
+        let high = (high.to_f64() - mean) / std;
+        Ok(Matrix::from_fn(rows, cols, |_, _| {
+            T::from_f64(mean + std * truncated_standard_normal(low, high, rng))
+        }))
+    }
+
+    // each element is 1 with probability p and 0 otherwise
+    pub fn bernoulli(rows: usize, cols: usize, p: f64, rng: &mut impl Rng) -> Result<Self, Error> {
+        // also rejects NaN
+        if !(0.0..=1.0).contains(&p) {
+            return Err(Error::InvalidArgument {
+                op: "bernoulli",
+                reason: "p must lie in [0, 1]",
+            });
+        }
+        Ok(Matrix::from_fn(rows, cols, |_, _| {
+            if rng.next_f64() < p {
+                T::one()
+            } else {
+                T::zero()
+            }
+        }))
+    }
+
+    // inverted dropout: drops each element with probability `rate` and scales
+    // the survivors by 1 / (1 - rate), so mask.hadamard(&x) keeps the
+    // expectation of x and inference needs no rescaling
+    pub fn dropout_mask(
+        rows: usize,
+        cols: usize,
+        rate: f64,
+        rng: &mut impl Rng,
+    ) -> Result<Self, Error> {
+        if !(0.0..1.0).contains(&rate) {
+            return Err(Error::InvalidArgument {
+                op: "dropout_mask",
+                reason: "rate must lie in [0, 1)",
+            });
+        }
+        let keep = 1.0 - rate;
+        let scale = T::from_f64(1.0 / keep);
+        Ok(Matrix::from_fn(rows, cols, |_, _| {
+            if rng.next_f64() < keep {
+                scale
+            } else {
+                T::zero()
+            }
+        }))
+    }
+
+    // Haar-distributed matrix with orthonormal columns (rows >= cols) or rows
+    // (rows < cols): the QR factor of a Gaussian matrix, with the signs of R's
+    // diagonal moved into Q so the distribution is uniform (Mezzadri, 2007)
+    pub fn random_orthogonal(rows: usize, cols: usize, rng: &mut impl Rng) -> Self {
+        if rows < cols {
+            return Matrix::random_orthogonal(cols, rows, rng).transpose();
+        }
+        let gaussian = Matrix::random_normal(rows, cols, T::zero(), T::one(), rng);
+        let (mut q, r) = qr(&gaussian).expect("qr of a tall matrix cannot fail");
+        for j in 0..cols {
+            if r.get(j, j) < T::zero() {
+                for i in 0..rows {
+                    q.set(i, j, -q.get(i, j));
+                }
+            }
+        }
+        q
+    }
+
+    // Glorot/Xavier initialization for a layer computing W * x, so a
+    // rows x cols weight has fan_in = cols and fan_out = rows; suits tanh and
+    // sigmoid layers
+    pub fn xavier_uniform(rows: usize, cols: usize, rng: &mut impl Rng) -> Self {
+        // an empty matrix has no fans; max(1) keeps the limit finite
+        let limit = T::from_f64((6.0 / (rows + cols).max(1) as f64).sqrt());
+        Matrix::random_uniform(rows, cols, -limit, limit, rng).expect("the Xavier limit is finite")
+    }
+
+    // He/Kaiming initialization, N(0, 2 / fan_in); suits ReLU layers
+    pub fn he_normal(rows: usize, cols: usize, rng: &mut impl Rng) -> Self {
+        let std = T::from_f64((2.0 / cols as f64).sqrt());
+        Matrix::random_normal(rows, cols, T::zero(), std, rng)
+    }
+}
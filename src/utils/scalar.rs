@@ -0,0 +1,187 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::matrix::gemm::{self, Kernel};
+
+// element type of a Matrix: f32, f64 or Complex<f32 / f64>
+pub trait Scalar:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + PartialEq
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+{
+    // the underlying real type, Self for real scalars
+    type Real: RealField;
+
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_real(value: Self::Real) -> Self;
+    fn from_f64(value: f64) -> Self;
+
+    fn real(self) -> Self::Real;
+    fn imag(self) -> Self::Real;
+    fn conj(self) -> Self;
+    // modulus, |x|
+    fn abs(self) -> Self::Real;
+    fn sqrt(self) -> Self;
+    fn powf(self, exponent: Self::Real) -> Self;
+
+    // microkernel used by gemm, picked once per call; f32 and f64 override it
+    // with SIMD kernels when the CPU supports them
+    fn gemm_kernel() -> Kernel<Self> {
+        gemm::kernel_generic::<Self>
+    }
+}
+
+// ordered real scalars; every decomposition is written against this
+pub trait RealField: Scalar<Real = Self> + PartialOrd {
+    const EPSILON: Self;
+    const MIN_POSITIVE: Self;
+    const MAX: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+
+    fn from_usize(value: usize) -> Self {
+        Self::from_f64(value as f64)
+    }
+    fn to_f64(self) -> f64;
+
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+    // ln(1 + self), accurate for small self
+    fn ln_1p(self) -> Self;
+    fn tanh(self) -> Self;
+    fn powi(self, exponent: i32) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn signum(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn is_finite(self) -> bool;
+    fn is_nan(self) -> bool;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_real {
+    ($t:ident, $kernel:path) => {
+        impl Scalar for $t {
+            type Real = $t;
+
+            fn zero() -> Self {
+                0.0
+            }
+            fn one() -> Self {
+                1.0
+            }
+            fn from_real(value: Self) -> Self {
+                value
+            }
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+            fn real(self) -> Self {
+                self
+            }
+            fn imag(self) -> Self {
+                0.0
+            }
+            fn conj(self) -> Self {
+                self
+            }
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+            fn powf(self, exponent: Self) -> Self {
+                $t::powf(self, exponent)
+            }
+            fn gemm_kernel() -> Kernel<Self> {
+                $kernel()
+            }
+        }
+
+        impl RealField for $t {
+            const EPSILON: Self = $t::EPSILON;
+            const MIN_POSITIVE: Self = $t::MIN_POSITIVE;
+            const MAX: Self = $t::MAX;
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+            fn ln_1p(self) -> Self {
+                $t::ln_1p(self)
+            }
+            fn tanh(self) -> Self {
+                $t::tanh(self)
+            }
+            fn powi(self, exponent: i32) -> Self {
+                $t::powi(self, exponent)
+            }
+            fn hypot(self, other: Self) -> Self {
+                $t::hypot(self, other)
+            }
+            fn signum(self) -> Self {
+                $t::signum(self)
+            }
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $t::total_cmp(self, other)
+            }
+        }
+    };
+}
+
+impl_real!(f32, gemm::kernel_f32);
+impl_real!(f64, gemm::kernel_f64);
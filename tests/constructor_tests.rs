@@ -0,0 +1,153 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::matrix::{Layout, Matrix};
+use linear_algebra_ml::utils::error::Error;
+use linear_algebra_ml::utils::random::{Rng, SplitMix64};
+
+#[test]
+fn test_filled_constructors() {
+    assert_eq!(Matrix::<f64>::ones(2, 2).data, vec![1.0; 4]);
+    assert_eq!(Matrix::full(1, 3, 7.5).data, vec![7.5; 3]);
+    assert_eq!(Matrix::from_fn(2, 3, |i, j| (10 * i + j) as f64), Matrix::new(2, 3, vec![
+        0.0,  1.0,  2.0,
+        10.0, 11.0, 12.0
+    ]));
+    assert_eq!(Matrix::from_diag(&[1.0, 2.0]), Matrix::new(2, 2, vec![
+        1.0, 0.0,
+        0.0, 2.0
+    ]));
+}
+
+#[test]
+fn test_eye_offsets() {
+    assert_eq!(Matrix::<f64>::eye(3, 3, 0), Matrix::identity(3));
+    assert_eq!(Matrix::<f64>::eye(2, 3, 1), Matrix::new(2, 3, vec![
+        0.0, 1.0, 0.0,
+        0.0, 0.0, 1.0
+    ]));
+    assert_eq!(Matrix::<f64>::eye(3, 2, -1), Matrix::new(3, 2, vec![
+        0.0, 0.0,
+        1.0, 0.0,
+        0.0, 1.0
+    ]));
+}
+
+#[test]
+fn test_from_rows_and_cols() {
+    let by_rows = Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+    let by_cols = Matrix::from_cols(&[[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]).unwrap();
+
+    assert_eq!(by_rows.shape(), (3, 2));
+    assert_eq!(by_cols.layout, Layout::ColMajor);
+    assert_eq!(by_rows, by_cols);
+    assert_eq!(Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0]]), Err(Error::DimensionMismatch {
+        op: "from_rows",
+        left: (1, 2),
+        right: (1, 1),
+    }));
+    assert_eq!(Matrix::<f64>::from_rows::<Vec<f64>>(&[]).unwrap().shape(), (0, 0));
+}
+
+#[test]
+fn test_linspace_and_arange() {
+    assert_eq!(Matrix::linspace(0.0, 1.0, 5).data, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    assert_eq!(Matrix::linspace(0.0, 0.3, 4).data[3], 0.3);
+    assert_eq!(Matrix::linspace(2.0, 5.0, 1).data, vec![2.0]);
+    assert_eq!(Matrix::arange(0.0, 2.0, 0.5).unwrap().data, vec![0.0, 0.5, 1.0, 1.5]);
+    assert_eq!(Matrix::arange(3.0, 0.0, -1.0).unwrap().shape(), (3, 1));
+    assert_eq!(Matrix::arange(0.0, 1.0, -1.0).unwrap().shape(), (0, 1));
+}
+
+#[test]
+fn test_arange_rejects_bad_arguments() {
+    let zero_step = Error::InvalidArgument { op: "arange", reason: "step must be non-zero" };
+    let not_finite = Error::InvalidArgument { op: "arange", reason: "start, stop and step must be finite" };
+
+    assert_eq!(Matrix::<f64>::arange(0.0, 1.0, 0.0), Err(zero_step));
+    assert_eq!(Matrix::arange(0.0, 1.0, f64::NAN), Err(not_finite.clone()));
+    assert_eq!(Matrix::arange(f64::NAN, 1.0, 0.5), Err(not_finite.clone()));
+    assert_eq!(Matrix::arange(0.0, f64::INFINITY, 0.5), Err(not_finite.clone()));
+    assert_eq!(Matrix::arange(0.0f32, 1.0, f32::NEG_INFINITY), Err(not_finite));
+    // rejected before anything is allocated
+    let too_many = Error::InvalidArgument { op: "arange", reason: "too many elements" };
+    assert_eq!(Matrix::arange(0.0, 1e300, 1e-300), Err(too_many.clone()));
+    assert_eq!(Matrix::arange(-f64::MAX, f64::MAX, 1.0), Err(too_many));
+}
+
+#[test]
+fn test_random_constructors_are_seeded() {
+    let a: Matrix = Matrix::random_uniform(4, 5, -1.0, 1.0, &mut SplitMix64::new(7)).unwrap();
+    let b: Matrix = Matrix::random_uniform(4, 5, -1.0, 1.0, &mut SplitMix64::new(7)).unwrap();
+    let c: Matrix = Matrix::random_uniform(4, 5, -1.0, 1.0, &mut SplitMix64::new(8)).unwrap();
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert!(a.data.iter().all(|&x| (-1.0..1.0).contains(&x)));
+
+    // one generator can be threaded through several constructors
+    let mut rng = SplitMix64::new(1);
+    let w: Matrix<f32> = Matrix::xavier_uniform(30, 20, &mut rng);
+    let limit = (6.0f32 / 50.0).sqrt();
+    assert!(w.data.iter().all(|&x| x.abs() <= limit));
+    let h: Matrix = Matrix::he_normal(200, 50, &mut rng);
+    let mean = h.data.iter().sum::<f64>() / h.data.len() as f64;
+    let var = h.data.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / h.data.len() as f64;
+    assert!(mean.abs() < 0.02);
+    assert!((var - 2.0 / 50.0).abs() < 0.004);
+}
+
+// a user-supplied generator only needs next_u64
+struct Counter(u64);
+
+impl Rng for Counter {
+    fn next_u64(&mut self) -> u64 {
+        self.0 += 1 << 62;
+        self.0
+    }
+}
+
+// replays fixed outputs, oldest first
+struct Replay(Vec<u64>);
+
+impl Rng for Replay {
+    fn next_u64(&mut self) -> u64 {
+        self.0.remove(0)
+    }
+}
+
+#[test]
+fn test_random_uniform_excludes_high() {
+    // 1 - 2^-53 rounds to exactly 1.0 in f32, so that draw is rejected
+    let m: Matrix<f32> = Matrix::random_uniform(1, 1, 0.0, 1.0, &mut Replay(vec![u64::MAX, 1 << 63])).unwrap();
+    assert_eq!(m.data, vec![0.5]);
+    let m: Matrix<f32> = Matrix::xavier_uniform(1, 5, &mut Replay(vec![u64::MAX, 0, 0, 0, 0, 0]));
+    assert_eq!(m.data, vec![-1.0; 5]);
+
+    // bounds one f32 step apart: redraws stay bounded and the result in range
+    let high = 1.0f32 + f32::EPSILON;
+    let m: Matrix<f32> = Matrix::random_uniform(1, 1, 1.0, high, &mut Replay(vec![u64::MAX; 64])).unwrap();
+    assert_eq!(m.data, vec![1.0]);
+    assert_eq!(Matrix::random_uniform(1, 2, 3.0, 3.0, &mut SplitMix64::new(0)).unwrap().data, vec![3.0; 2]);
+}
+
+#[test]
+fn test_random_uniform_rejects_bad_bounds() {
+    let mut rng = SplitMix64::new(0);
+    let invalid = |reason| Err(Error::InvalidArgument { op: "random_uniform", reason });
+
+    assert_eq!(Matrix::random_uniform(1, 1, 0.0, f64::INFINITY, &mut rng), invalid("low and high must be finite"));
+    assert_eq!(Matrix::random_uniform(1, 1, f64::NEG_INFINITY, 0.0, &mut rng), invalid("low and high must be finite"));
+    assert_eq!(Matrix::random_uniform(1, 1, f64::NAN, 1.0, &mut rng), invalid("low and high must be finite"));
+    assert_eq!(Matrix::random_uniform(1, 1, 2.0, 1.0, &mut rng), invalid("low must not exceed high"));
+    assert_eq!(Matrix::random_uniform(1, 1, -f64::MAX, f64::MAX, &mut rng), invalid("high - low must be finite"));
+}
+
+#[test]
+fn test_pluggable_rng() {
+    let m: Matrix = Matrix::random_uniform(1, 3, 0.0, 4.0, &mut Counter(0)).unwrap();
+    assert_eq!(m.data, vec![1.0, 2.0, 3.0]);
+
+    let mut boxed: Box<dyn Rng> = Box::new(SplitMix64::new(0));
+    let n: Matrix = Matrix::random_normal(2, 2, 0.0, 1.0, &mut boxed.as_mut());
+    assert!(n.data.iter().all(|x| x.is_finite()));
+}
@@ -3,6 +3,9 @@
 use linear_algebra_ml::matrix::matrix::Matrix;
 use linear_algebra_ml::matrix::ops;
 use linear_algebra_ml::matrix::decompositions;
+use linear_algebra_ml::matrix::decompositions::{CholeskyFactor, LuFactor, QrFactor};
+use linear_algebra_ml::utils::complex::Complex;
+use linear_algebra_ml::utils::error::Error;
 
 #[test]
 fn test_lu() {
@@ -12,14 +15,512 @@ fn test_lu() {
         6.0, 18.0, 5.0
     ]);
 
-    match decompositions::lu(&a) {
-        Ok((l, u)) => {
-            println!("Lower matrix:\n{}", l);
-            println!("Upper matrix:\n{}", u);
-            let result = ops::mul(&l, &u).unwrap();
-            println!("Result:\n{}", result);
-            assert_eq!(a, result);
+    let (l, u, perm, sign) = decompositions::lu(&a).unwrap();
+    println!("Lower matrix:\n{}", l);
+    println!("Upper matrix:\n{}", u);
+    let result = ops::mul(&l, &u).unwrap();
+    println!("Result:\n{}", result);
+    assert_close(&result, &permute_rows(&a, &perm), 1e-12);
+    assert_eq!(perm, vec![2, 1, 0]);
+    assert_eq!(sign, -1.0);
+}
+
+#[test]
+fn test_lu_needs_pivoting() {
+    let a = Matrix::new(2, 2, vec![
+        0.0, 1.0,
+        1.0, 0.0
+    ]);
+
+    let (l, u, perm, sign) = decompositions::lu(&a).unwrap();
+    assert_eq!(l, Matrix::identity(2));
+    assert_eq!(u, Matrix::identity(2));
+    assert_eq!(perm, vec![1, 0]);
+    assert_eq!(sign, -1.0);
+}
+
+#[test]
+fn test_lu_full_pivoting() {
+    let a: Matrix = Matrix::new(3, 3, vec![
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        1.0, 0.0, 9.0
+    ]);
+
+    let (l, u, rows, cols, _) = decompositions::lu_full(&a).unwrap();
+    assert_eq!(u.get(0, 0), 9.0);
+    assert!(u.get(2, 2).abs() < 1e-12);
+
+    let mut paq = Matrix::zeros(3, 3);
+    for (i, &r) in rows.iter().enumerate() {
+        for (j, &c) in cols.iter().enumerate() {
+            paq.set(i, j, a.get(r, c));
+        }
+    }
+    assert_close(&ops::mul(&l, &u).unwrap(), &paq, 1e-12);
+}
+
+fn assert_close(a: &Matrix, b: &Matrix, tol: f64) {
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+    for (x, y) in a.data.iter().zip(&b.data) {
+        assert!((x - y).abs() < tol, "{} != {}", x, y);
+    }
+}
+
+fn permute_rows(a: &Matrix, perm: &[usize]) -> Matrix {
+    let mut result = Matrix::zeros(a.rows, a.cols);
+    for (i, &p) in perm.iter().enumerate() {
+        for j in 0..a.cols {
+            result.set(i, j, a.get(p, j));
+        }
+    }
+    result
+}
+
+fn reconstruct(u: &Matrix, s: &[f64], vt: &Matrix) -> Matrix {
+    let mut sigma = Matrix::zeros(u.cols, vt.rows);
+    for (i, value) in s.iter().enumerate() {
+        sigma.set(i, i, *value);
+    }
+    ops::mul(&ops::mul(u, &sigma).unwrap(), vt).unwrap()
+}
+
+fn assert_orthonormal_cols(q: &Matrix) {
+    let qtq = ops::mul(&ops::transpose(q), q).unwrap();
+    assert_close(&qtq, &Matrix::identity(q.cols), 1e-10);
+}
+
+#[test]
+fn test_svd_tall() {
+    let a = Matrix::new(4, 3, vec![
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        7.0, 8.0, 10.0,
+        -1.0, 0.5, 2.0
+    ]);
+
+    let (u, s, vt) = decompositions::svd(&a).unwrap();
+    assert_eq!((u.rows, u.cols, s.len(), vt.rows, vt.cols), (4, 3, 3, 3, 3));
+    assert!(s.windows(2).all(|w| w[0] >= w[1]));
+    assert_orthonormal_cols(&u);
+    assert_orthonormal_cols(&ops::transpose(&vt));
+    assert_close(&reconstruct(&u, &s, &vt), &a, 1e-10);
+}
+
+#[test]
+fn test_svd_wide_full() {
+    let a: Matrix = Matrix::new(2, 3, vec![
+        3.0, 2.0, 2.0,
+        2.0, 3.0, -2.0
+    ]);
+
+    let (u, s, vt) = decompositions::svd_full(&a).unwrap();
+    assert_eq!((u.rows, u.cols, vt.rows, vt.cols), (2, 2, 3, 3));
+    assert!((s[0] - 5.0).abs() < 1e-12);
+    assert!((s[1] - 3.0).abs() < 1e-12);
+    assert_orthonormal_cols(&u);
+    assert_orthonormal_cols(&vt);
+    assert_close(&reconstruct(&u, &s, &vt), &a, 1e-10);
+}
+
+#[test]
+fn test_svd_rank_deficient() {
+    let a: Matrix = Matrix::new(3, 3, vec![
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        1.0, 1.0, 1.0
+    ]);
+
+    let (u, s, vt) = decompositions::svd_full(&a).unwrap();
+    assert!(s[2].abs() < 1e-12);
+    assert_orthonormal_cols(&u);
+    assert_close(&reconstruct(&u, &s, &vt), &a, 1e-10);
+}
+
+#[test]
+fn test_qr_economy() {
+    let a = Matrix::new(4, 3, vec![
+        12.0, -51.0, 4.0,
+        6.0, 167.0, -68.0,
+        -4.0, 24.0, -41.0,
+        1.0, 1.0, 1.0
+    ]);
+
+    let (q, r) = decompositions::qr(&a).unwrap();
+    assert_eq!((q.rows, q.cols, r.rows, r.cols), (4, 3, 3, 3));
+    assert_orthonormal_cols(&q);
+    for i in 0..r.rows {
+        for j in 0..i {
+            assert_eq!(r.get(i, j), 0.0);
         }
-        Err(e) => println!("LU decomposition failed: {:?}", e),
     }
-}
\ No newline at end of file
+    assert_close(&ops::mul(&q, &r).unwrap(), &a, 1e-10);
+}
+
+#[test]
+fn test_qr_full() {
+    let a = Matrix::new(3, 2, vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ]);
+
+    let (q, r) = decompositions::qr_full(&a).unwrap();
+    assert_eq!((q.rows, q.cols, r.rows, r.cols), (3, 3, 3, 2));
+    assert_orthonormal_cols(&q);
+    assert_close(&ops::mul(&q, &r).unwrap(), &a, 1e-10);
+}
+
+#[test]
+fn test_qr_pivoted_rank() {
+    // third column is the sum of the first two
+    let a: Matrix = Matrix::new(4, 3, vec![
+        1.0, 0.0, 1.0,
+        0.0, 1.0, 1.0,
+        2.0, 1.0, 3.0,
+        1.0, 3.0, 4.0
+    ]);
+
+    let (q, r, perm, rank) = decompositions::qr_pivoted(&a).unwrap();
+    assert_eq!(rank, 2);
+    assert_eq!(perm[0], 2);
+    assert!(r.get(0, 0).abs() >= r.get(1, 1).abs());
+
+    let mut permuted = Matrix::zeros(4, 3);
+    for i in 0..4 {
+        for (j, &p) in perm.iter().enumerate() {
+            permuted.set(i, j, a.get(i, p));
+        }
+    }
+    assert_close(&ops::mul(&q, &r).unwrap(), &permuted, 1e-10);
+}
+
+#[test]
+fn test_eigen_general() {
+    // one real eigenvalue and a complex pair
+    let a = Matrix::new(4, 4, vec![
+        4.0, -5.0, 0.0, 3.0,
+        0.0, 4.0, -3.0, -5.0,
+        5.0, -3.0, 4.0, 0.0,
+        3.0, 0.0, 5.0, 4.0
+    ]);
+
+    let (values, vectors) = decompositions::eigen(&a).unwrap();
+    assert_eq!(values.len(), 4);
+    let trace: f64 = values.iter().map(|v| v.re).sum();
+    assert!((trace - 16.0).abs() < 1e-10);
+
+    // check A v = lambda v for every (possibly complex) pair
+    let a = a.cast(Complex::from);
+    let av = ops::mul(&a, &vectors).unwrap();
+    for (k, &lambda) in values.iter().enumerate() {
+        for i in 0..4 {
+            assert!((av.get(i, k) - lambda * vectors.get(i, k)).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_eigenvalues_companion() {
+    // companion matrix of (x - 1)(x - 2)(x - 3)(x + 4)
+    let a: Matrix = Matrix::new(4, 4, vec![
+        2.0, 13.0, -38.0, 24.0,
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0
+    ]);
+
+    let values = decompositions::eigenvalues(&a).unwrap();
+    for (value, expected) in values.iter().zip([3.0, 2.0, 1.0, -4.0]) {
+        assert!((value.re - expected).abs() < 1e-9);
+        assert!(value.im.abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_eigen_symmetric() {
+    let a = Matrix::new(4, 4, vec![
+        4.0, 1.0, -2.0, 2.0,
+        1.0, 2.0, 0.0, 1.0,
+        -2.0, 0.0, 3.0, -2.0,
+        2.0, 1.0, -2.0, -1.0
+    ]);
+
+    let (values, vectors) = decompositions::eigen_symmetric(&a).unwrap();
+    assert!(values.windows(2).all(|w| w[0] >= w[1]));
+    assert_orthonormal_cols(&vectors);
+
+    // A V = V diag(values)
+    let av = ops::mul(&a, &vectors).unwrap();
+    let mut vd = vectors.clone();
+    for i in 0..4 {
+        for (j, value) in values.iter().enumerate() {
+            vd.set(i, j, vectors.get(i, j) * value);
+        }
+    }
+    assert_close(&av, &vd, 1e-10);
+}
+
+#[test]
+fn test_eigen_symmetric_top_k() {
+    let a = Matrix::new(5, 5, vec![
+        6.0, 2.0, 1.0, 0.0, 1.0,
+        2.0, 5.0, 2.0, 1.0, 0.0,
+        1.0, 2.0, 4.0, 2.0, 1.0,
+        0.0, 1.0, 2.0, 3.0, 2.0,
+        1.0, 0.0, 1.0, 2.0, 2.0
+    ]);
+
+    let (all_values, _) = decompositions::eigen_symmetric(&a).unwrap();
+    let (values, vectors) = decompositions::eigen_symmetric_top_k(&a, 2).unwrap();
+    assert_eq!((vectors.rows, vectors.cols), (5, 2));
+    assert_orthonormal_cols(&vectors);
+    for k in 0..2 {
+        assert!((values[k] - all_values[k]).abs() < 1e-10);
+        for i in 0..5 {
+            let av: f64 = (0..5).map(|j| a.get(i, j) * vectors.get(j, k)).sum();
+            assert!((av - values[k] * vectors.get(i, k)).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_eigen_symmetric_rejects_asymmetric() {
+    let a = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        3.0, 4.0
+    ]);
+    assert!(decompositions::eigen_symmetric(&a).is_err());
+}
+
+fn permute_symmetric(a: &Matrix, perm: &[usize]) -> Matrix {
+    let mut result = Matrix::zeros(a.rows, a.cols);
+    for (i, &p) in perm.iter().enumerate() {
+        for (j, &q) in perm.iter().enumerate() {
+            result.set(i, j, a.get(p, q));
+        }
+    }
+    result
+}
+
+#[test]
+fn test_cholesky() {
+    let a = Matrix::new(3, 3, vec![
+        4.0, 12.0, -16.0,
+        12.0, 37.0, -43.0,
+        -16.0, -43.0, 98.0
+    ]);
+
+    let l = decompositions::cholesky(&a).unwrap();
+    let expected = Matrix::new(3, 3, vec![
+        2.0, 0.0, 0.0,
+        6.0, 1.0, 0.0,
+        -8.0, 5.0, 3.0
+    ]);
+    assert_close(&l, &expected, 1e-12);
+    assert_close(&ops::mul(&l, &ops::transpose(&l)).unwrap(), &a, 1e-12);
+}
+
+#[test]
+fn test_cholesky_not_positive_definite() {
+    let a = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        2.0, 1.0
+    ]);
+    assert!(decompositions::cholesky(&a).is_err());
+}
+
+#[test]
+fn test_cholesky_pivoted_semidefinite() {
+    // rank 2: B * B^T with B 3x2
+    let b = Matrix::new(3, 2, vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0
+    ]);
+    let a = ops::mul(&b, &ops::transpose(&b)).unwrap();
+
+    let (l, perm, rank) = decompositions::cholesky_pivoted(&a).unwrap();
+    assert_eq!(rank, 2);
+    assert_eq!(perm[0], 2);
+    assert_close(
+        &ops::mul(&l, &ops::transpose(&l)).unwrap(),
+        &permute_symmetric(&a, &perm),
+        1e-10,
+    );
+}
+
+#[test]
+fn test_cholesky_pivoted_indefinite() {
+    // eigenvalues 3 and -1; the first step leaves a Schur complement of -3
+    let indefinite: Matrix = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        2.0, 1.0
+    ]);
+    assert_eq!(decompositions::cholesky_pivoted(&indefinite), Err(Error::NotPositiveDefinite { pivot: 1 }));
+
+    let negative: Matrix = Matrix::from_diag(&[-1.0, -2.0]);
+    assert_eq!(decompositions::cholesky_pivoted(&negative), Err(Error::NotPositiveDefinite { pivot: 0 }));
+
+    // a zero Schur complement with a large off-diagonal is indefinite too
+    let zero_diag: Matrix = Matrix::new(3, 3, vec![
+        4.0, 0.0, 0.0,
+        0.0, 0.0, 1.0,
+        0.0, 1.0, 0.0
+    ]);
+    assert_eq!(decompositions::cholesky_pivoted(&zero_diag), Err(Error::NotPositiveDefinite { pivot: 1 }));
+}
+
+#[test]
+fn test_ldlt_indefinite() {
+    // zero diagonal forces a 2x2 pivot block
+    let a = Matrix::new(4, 4, vec![
+        0.0, 1.0, 2.0, 3.0,
+        1.0, 0.0, 4.0, 5.0,
+        2.0, 4.0, 0.0, 6.0,
+        3.0, 5.0, 6.0, 0.0
+    ]);
+
+    let (l, d, perm) = decompositions::ldlt(&a).unwrap();
+    for i in 0..4 {
+        assert_eq!(l.get(i, i), 1.0);
+        for j in (i + 1)..4 {
+            assert_eq!(l.get(i, j), 0.0);
+        }
+    }
+    assert!(d.get(1, 0) != 0.0 || d.get(2, 1) != 0.0 || d.get(3, 2) != 0.0);
+    let ldl = ops::mul(&ops::mul(&l, &d).unwrap(), &ops::transpose(&l)).unwrap();
+    assert_close(&ldl, &permute_symmetric(&a, &perm), 1e-10);
+}
+
+#[test]
+fn test_lu_factor_reuse() {
+    let a = Matrix::new(3, 3, vec![
+        0.0, 2.0, 1.0,
+        1.0, 1.0, 0.0,
+        3.0, 0.0, 1.0
+    ]);
+    let factor = LuFactor::new(&a).unwrap();
+
+    // two right-hand sides at once
+    let b = Matrix::new(3, 2, vec![
+        3.0, 1.0,
+        2.0, 0.0,
+        4.0, 2.0
+    ]);
+    let x = factor.solve(&b).unwrap();
+    assert_close(&ops::mul(&a, &x).unwrap(), &b, 1e-12);
+
+    assert!((factor.determinant() + 5.0).abs() < 1e-12);
+    let inverse = factor.inverse().unwrap();
+    assert_close(&ops::mul(&a, &inverse).unwrap(), &Matrix::identity(3), 1e-12);
+
+    // Hager's estimate is exact for small matrices like this one
+    let inv_norm = (0..3)
+        .map(|j| (0..3).map(|i| inverse.get(i, j).abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    assert!((factor.rcond() - 1.0 / (4.0 * inv_norm)).abs() < 1e-12);
+}
+
+#[test]
+fn test_lu_factor_singular() {
+    let a = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        2.0, 4.0
+    ]);
+    let factor = LuFactor::new(&a).unwrap();
+    assert_eq!(factor.determinant(), 0.0);
+    assert_eq!(factor.rcond(), 0.0);
+    assert!(factor.solve(&Matrix::identity(2)).is_err());
+}
+
+#[test]
+fn test_qr_factor_least_squares() {
+    // fit y = 1 + 2x through noisy-free points
+    let a: Matrix = Matrix::new(4, 2, vec![
+        1.0, 0.0,
+        1.0, 1.0,
+        1.0, 2.0,
+        1.0, 3.0
+    ]);
+    let b = Matrix::new(4, 1, vec![1.0, 3.0, 5.0, 7.0]);
+
+    let factor = QrFactor::new(&a).unwrap();
+    let x = factor.solve(&b).unwrap();
+    assert_close(&x, &Matrix::new(2, 1, vec![1.0, 2.0]), 1e-12);
+    assert!(factor.determinant().is_err());
+    assert!(factor.rcond() > 0.0);
+
+    let square: Matrix = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        3.0, 4.0
+    ]);
+    let factor = QrFactor::new(&square).unwrap();
+    assert!((factor.determinant().unwrap() + 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_qr_factor_rank_deficient() {
+    // third column is the sum of the first two, so R[2][2] is rounding noise
+    let a: Matrix = Matrix::new(4, 3, vec![
+        1.0, 0.0, 1.0,
+        0.0, 1.0, 1.0,
+        2.0, 1.0, 3.0,
+        1.0, 3.0, 4.0
+    ]);
+
+    let factor = QrFactor::new(&a).unwrap();
+    assert!(!factor.is_full_rank());
+    assert_eq!(factor.rcond(), 0.0);
+    assert_eq!(factor.solve(&Matrix::new(4, 1, vec![1.0; 4])), Err(Error::SingularMatrix { pivot: 2 }));
+
+    // a tiny leading column must not shrink the tolerance for the others
+    let factor = QrFactor::new(&Matrix::<f64>::from_diag(&[1e-20, 1.0])).unwrap();
+    assert!(!factor.is_full_rank());
+    assert_eq!(factor.solve(&Matrix::new(2, 1, vec![1.0; 2])), Err(Error::SingularMatrix { pivot: 0 }));
+}
+
+#[test]
+fn test_cholesky_factor() {
+    let a: Matrix = Matrix::new(3, 3, vec![
+        4.0, 12.0, -16.0,
+        12.0, 37.0, -43.0,
+        -16.0, -43.0, 98.0
+    ]);
+    let factor = CholeskyFactor::new(&a).unwrap();
+
+    assert!((factor.determinant() - 36.0).abs() < 1e-9);
+    let b = Matrix::new(3, 1, vec![1.0, 2.0, 3.0]);
+    let x = factor.solve(&b).unwrap();
+    assert_close(&ops::mul(&a, &x).unwrap(), &b, 1e-10);
+    let inverse = factor.inverse().unwrap();
+    assert_close(&ops::mul(&a, &inverse).unwrap(), &Matrix::identity(3), 1e-9);
+    assert!(factor.rcond() > 0.0 && factor.rcond() < 1.0);
+}
+
+#[test]
+fn test_decompositions_f32() {
+    let a: Matrix<f32> = Matrix::new(3, 2, vec![
+        3.0, 1.0,
+        1.0, 3.0,
+        0.0, 2.0
+    ]);
+
+    let (u, s, vt) = decompositions::svd(&a).unwrap();
+    let mut us = u.clone();
+    for (j, &sj) in s.iter().enumerate() {
+        for i in 0..us.rows {
+            us.set(i, j, u.get(i, j) * sj);
+        }
+    }
+    let back = ops::mul(&us, &vt).unwrap();
+    for (x, y) in back.data.iter().zip(&a.data) {
+        assert!((x - y).abs() < 1e-5);
+    }
+
+    let gram = ops::mul(&ops::transpose(&a), &a).unwrap();
+    let (values, _) = decompositions::eigen_symmetric(&gram).unwrap();
+    for (value, sv) in values.iter().zip(&s) {
+        assert!((value - sv * sv).abs() < 1e-4);
+    }
+}
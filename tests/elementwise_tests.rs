@@ -0,0 +1,140 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::elementwise;
+use linear_algebra_ml::matrix::matrix::{Layout, Matrix};
+use linear_algebra_ml::utils::error::Error;
+
+fn close(a: &Matrix, b: &[f64]) -> bool {
+    a.data.len() == b.len() && a.data.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-12)
+}
+
+#[test]
+fn test_map_and_map_inplace() {
+    let a = Matrix::new(2, 2, vec![
+        1.0, -2.0,
+        3.0, -4.0
+    ]);
+
+    assert_eq!(a.map(|x| x * 10.0).data, vec![10.0, -20.0, 30.0, -40.0]);
+    let signs: Matrix<f32> = a.map(|x| if x > 0.0 { 1.0 } else { -1.0 });
+    assert_eq!(signs.data, vec![1.0, -1.0, 1.0, -1.0]);
+
+    let mut b = a.clone().transpose();
+    b.map_inplace(|x| x + 1.0);
+    assert_eq!(b.layout, Layout::ColMajor);
+    assert_eq!(b, Matrix::new(2, 2, vec![
+        2.0,  4.0,
+        -1.0, -3.0
+    ]));
+}
+
+#[test]
+fn test_zip_map_and_apply() {
+    let a = Matrix::new(2, 3, vec![
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0
+    ]);
+    let row = Matrix::new(1, 3, vec![3.0, 2.0, 1.0]);
+
+    assert_eq!(a.zip_map(&row, f64::max).unwrap().data, vec![3.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(a.zip_map(&a.row(1), |x, y| y - x).unwrap().data, vec![3.0, 3.0, 3.0, 0.0, 0.0, 0.0]);
+    assert_eq!(a.zip_map(&Matrix::<f64>::zeros(3, 3), |x, _| x), Err(Error::DimensionMismatch {
+        op: "zip_map",
+        left: (2, 3),
+        right: (3, 3),
+    }));
+
+    // keep the upper triangle, counting visits
+    let mut upper = a.clone();
+    let mut visited = 0;
+    upper.apply(|i, j, x| {
+        visited += 1;
+        if j >= i { x } else { 0.0 }
+    });
+    assert_eq!(visited, 6);
+    assert_eq!(upper.data, vec![1.0, 2.0, 3.0, 0.0, 5.0, 6.0]);
+}
+
+#[test]
+fn test_math_functions() {
+    let a = Matrix::new(1, 4, vec![-4.0, -1.0, 0.0, 2.25]);
+
+    assert_eq!(elementwise::abs(&a).data, vec![4.0, 1.0, 0.0, 2.25]);
+    assert_eq!(elementwise::sqrt(&elementwise::abs(&a)).data, vec![2.0, 1.0, 0.0, 1.5]);
+    assert!(close(&elementwise::ln(&elementwise::exp(&a)), &a.data));
+    assert_eq!(elementwise::clamp(&a, -2.0, 1.0).unwrap().data, vec![-2.0, -1.0, 0.0, 1.0]);
+    assert_eq!(elementwise::clamp(&a, 0.0, f64::INFINITY).unwrap().data, vec![0.0, 0.0, 0.0, 2.25]);
+    assert_eq!(elementwise::clamp(&a, 2.0, 1.0), Err(Error::InvalidArgument {
+        op: "clamp",
+        reason: "low must not exceed high",
+    }));
+    assert_eq!(elementwise::clamp(&a, f64::NAN, 1.0), Err(Error::InvalidArgument {
+        op: "clamp",
+        reason: "low and high must not be NaN",
+    }));
+    assert!(elementwise::clamp(&a, 0.0, f64::NAN).is_err());
+    assert!(close(&elementwise::tanh(&a), &a.data.iter().map(|x| x.tanh()).collect::<Vec<_>>()));
+}
+
+#[test]
+fn test_activations() {
+    let a: Matrix = Matrix::new(1, 5, vec![-800.0, -1.0, 0.0, 1.0, 800.0]);
+
+    assert_eq!(elementwise::relu(&a).data, vec![0.0, 0.0, 0.0, 1.0, 800.0]);
+    assert!(elementwise::relu(&Matrix::new(1, 1, vec![f64::NAN])).data[0].is_nan());
+    assert_eq!(elementwise::leaky_relu(&a, 0.01).data, vec![-8.0, -0.01, 0.0, 1.0, 800.0]);
+
+    // no overflow in either tail
+    let s = elementwise::sigmoid(&a);
+    assert_eq!((s.data[0], s.data[2], s.data[4]), (0.0, 0.5, 1.0));
+    assert!((s.data[1] + s.data[3] - 1.0).abs() < 1e-15);
+
+    let sp = elementwise::softplus(&a);
+    assert_eq!((sp.data[0], sp.data[4]), (0.0, 800.0));
+    assert!((sp.data[2] - 2.0f64.ln()).abs() < 1e-15);
+    assert!((sp.data[3] - sp.data[1] - 1.0).abs() < 1e-15);
+
+    let g = elementwise::gelu(&a);
+    assert_eq!((g.data[0], g.data[2], g.data[4]), (-0.0, 0.0, 800.0));
+    // x * Phi(x) at 1 is 0.841345
+    assert!((g.data[3] - 0.841345).abs() < 1e-3);
+}
+
+#[test]
+fn test_softmax_is_stable() {
+    let logits = Matrix::new(3, 3, vec![
+        1.0,    2.0,    3.0,
+        1001.0, 1002.0, 1003.0,
+        -1e4,   0.0,    f64::NEG_INFINITY
+    ]);
+    let p = elementwise::softmax(&logits);
+    let expected = [0.09003057317038046, 0.24472847105479764, 0.6652409557748219];
+
+    assert!(close(&p.slice(0..2, 0..3).to_matrix(), &[expected, expected].concat()));
+    assert_eq!(p.row(2).to_matrix().data, vec![0.0, 1.0, 0.0]);
+
+    let log_p = elementwise::log_softmax(&logits);
+    assert!(close(&log_p.slice(0..2, 0..3).to_matrix(), &[expected, expected].concat().iter().map(|x| x.ln()).collect::<Vec<_>>()));
+    // finite where ln(softmax) would underflow to -inf
+    assert_eq!(log_p.get(2, 0), -1e4);
+    assert_eq!(log_p.get(2, 2), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_softmax_infinite_rows() {
+    let logits = Matrix::new(3, 3, vec![
+        f64::INFINITY, 1.0,           f64::NEG_INFINITY,
+        f64::INFINITY, f64::INFINITY, 5.0,
+        f64::INFINITY, f64::NAN,      0.0
+    ]);
+
+    // one-hot on the +inf entries, shared when there are several
+    let p = elementwise::softmax(&logits);
+    assert_eq!(p.slice(0..2, 0..3).to_matrix().data, vec![1.0, 0.0, 0.0, 0.5, 0.5, 0.0]);
+    assert!(p.row(2).iter().all(|x| x.is_nan()));
+
+    let log_p = elementwise::log_softmax(&logits);
+    assert_eq!(log_p.row(0).to_matrix().data, vec![0.0, f64::NEG_INFINITY, f64::NEG_INFINITY]);
+    assert_eq!(log_p.row(1).to_matrix().data, vec![-2.0f64.ln(), -2.0f64.ln(), f64::NEG_INFINITY]);
+    assert!(log_p.row(2).iter().all(|x| x.is_nan()));
+}
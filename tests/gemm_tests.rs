@@ -0,0 +1,100 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::gemm::{gemm, Transpose};
+use linear_algebra_ml::matrix::matrix::Matrix;
+use linear_algebra_ml::matrix::ops;
+use linear_algebra_ml::utils::complex::Complex;
+
+// deterministic, non-symmetric fill
+fn filled(rows: usize, cols: usize, seed: usize) -> Matrix {
+    let data = (0..rows * cols)
+        .map(|i| (((i * 37 + seed * 101) % 97) as f64 - 48.0) / 16.0)
+        .collect();
+    Matrix::new(rows, cols, data)
+}
+
+fn reference(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut result = Matrix::zeros(a.rows, b.cols);
+    for i in 0..a.rows {
+        for j in 0..b.cols {
+            let value = (0..a.cols).map(|k| a.get(i, k) * b.get(k, j)).sum();
+            result.set(i, j, value);
+        }
+    }
+    result
+}
+
+fn assert_close(a: &Matrix, b: &Matrix, tol: f64) {
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+    for (x, y) in a.data.iter().zip(&b.data) {
+        assert!((x - y).abs() < tol, "{} vs {}", x, y);
+    }
+}
+
+#[test]
+fn test_mul_crosses_block_edges() {
+    // 130 rows and k = 300 span more than one MC and KC block, 17 columns
+    // leave a ragged NR edge
+    let a = filled(130, 300, 1);
+    let b = filled(300, 17, 2);
+
+    assert_close(&ops::mul(&a, &b).unwrap(), &reference(&a, &b), 1e-9);
+}
+
+#[test]
+fn test_gemm_transposes_alpha_beta() {
+    let a = filled(7, 5, 3);
+    let b = filled(5, 9, 4);
+    let c0 = filled(7, 9, 5);
+    let expected = ops::add(
+        &ops::scalar_mul(&reference(&a, &b), 2.0),
+        &ops::scalar_mul(&c0, -0.5),
+    ).unwrap();
+
+    let at = ops::transpose(&a);
+    let bt = ops::transpose(&b);
+    let cases = [
+        (&a, Transpose::No, &b, Transpose::No),
+        (&at, Transpose::Yes, &b, Transpose::No),
+        (&a, Transpose::No, &bt, Transpose::Yes),
+        (&at, Transpose::Yes, &bt, Transpose::Yes),
+    ];
+    for (a, trans_a, b, trans_b) in cases {
+        let mut c = c0.clone();
+        gemm(2.0, a, trans_a, b, trans_b, -0.5, &mut c).unwrap();
+        assert_close(&c, &expected, 1e-12);
+    }
+}
+
+#[test]
+fn test_gemm_beta_zero_ignores_c() {
+    let a = filled(3, 4, 6);
+    let b = filled(4, 2, 7);
+    let mut c = Matrix::new(3, 2, vec![f64::NAN; 6]);
+
+    gemm(1.0, &a, Transpose::No, &b, Transpose::No, 0.0, &mut c).unwrap();
+    assert_close(&c, &reference(&a, &b), 1e-12);
+}
+
+#[test]
+fn test_gemm_size_mismatch() {
+    let a = filled(3, 4, 8);
+    let mut c = Matrix::zeros(3, 3);
+
+    assert!(gemm(1.0, &a, Transpose::No, &a, Transpose::No, 0.0, &mut c).is_err());
+    assert!(gemm(1.0, &a, Transpose::No, &a, Transpose::Yes, 0.0, &mut c).is_ok());
+}
+
+#[test]
+fn test_gemm_f32_and_complex() {
+    let a = filled(11, 13, 9);
+    let b = filled(13, 10, 10);
+    let expected = reference(&a, &b);
+
+    let product = ops::mul(&a.cast(|x| x as f32), &b.cast(|x| x as f32)).unwrap();
+    assert_close(&product.cast(|x| x as f64), &expected, 1e-3);
+
+    let product = ops::mul(&a.cast(Complex::from), &b.cast(Complex::from)).unwrap();
+    assert_close(&product.cast(|z| z.re), &expected, 1e-9);
+    assert!(product.data.iter().all(|z| z.im == 0.0));
+}
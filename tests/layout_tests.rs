@@ -0,0 +1,115 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::gemm::{gemm, Transpose};
+use linear_algebra_ml::matrix::matrix::{Layout, Matrix};
+use linear_algebra_ml::matrix::ops;
+
+// the 2x3 matrix
+//   1 2 3
+//   4 5 6
+// stored column by column
+fn col_major() -> Matrix {
+    Matrix::from_col_major(2, 3, vec![
+        1.0, 4.0,
+        2.0, 5.0,
+        3.0, 6.0
+    ])
+}
+
+fn row_major() -> Matrix {
+    Matrix::new(2, 3, vec![
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0
+    ])
+}
+
+#[test]
+fn test_col_major_wraps_without_copy() {
+    let a = col_major();
+
+    assert_eq!(a.layout, Layout::ColMajor);
+    assert_eq!(a.strides(), (1, 2));
+    assert_eq!(a.get(0, 2), 3.0);
+    assert_eq!(a[(1, 0)], 4.0);
+    assert_eq!(a, row_major());
+    assert_eq!(a.to_layout(Layout::RowMajor).data, row_major().data);
+    assert_eq!(a.row(1).to_matrix().data, vec![4.0, 5.0, 6.0]);
+    assert_eq!(format!("{}", a), format!("{}", row_major()));
+}
+
+#[test]
+fn test_transpose_is_o1() {
+    let a = row_major();
+    let buffer = a.data.as_ptr();
+    let t = a.transpose();
+
+    assert_eq!(t.data.as_ptr(), buffer);
+    assert_eq!((t.rows, t.cols, t.layout), (3, 2, Layout::ColMajor));
+    assert_eq!(t, ops::transpose(&row_major()));
+    assert_eq!(t.transpose(), row_major());
+    // a column-major matrix transposes to a row-major copy of the same buffer
+    assert_eq!(ops::transpose(&col_major()).data, vec![
+        1.0, 4.0,
+        2.0, 5.0,
+        3.0, 6.0
+    ]);
+}
+
+#[test]
+fn test_elementwise_ops_keep_layout() {
+    let a = col_major();
+
+    let sum = ops::add(&a, &a).unwrap();
+    assert_eq!(sum.layout, Layout::ColMajor);
+    assert_eq!(sum, ops::scalar_mul(&row_major(), 2.0));
+
+    // mixed layouts fall back to a row-major result
+    let mixed = ops::sub(&a, &row_major()).unwrap();
+    assert_eq!(mixed.layout, Layout::RowMajor);
+    assert_eq!(mixed.data, vec![0.0; 6]);
+
+    let mut b = col_major();
+    b += Matrix::new(1, 3, vec![1.0, 1.0, 1.0]);
+    b[(0, 0)] = 0.0;
+    assert_eq!(b, Matrix::new(2, 3, vec![
+        0.0, 3.0, 4.0,
+        5.0, 6.0, 7.0
+    ]));
+}
+
+#[test]
+fn test_products_with_col_major_operands() {
+    let a = col_major();
+    let at = row_major().transpose();
+    let expected = ops::mul(&row_major(), &ops::transpose(&row_major())).unwrap();
+
+    assert_eq!(ops::mul(&a, &at).unwrap(), expected);
+
+    // column-major output is filled through its transpose
+    let mut c = Matrix::from_col_major(2, 2, vec![1.0; 4]);
+    gemm(1.0, &a, Transpose::No, &a, Transpose::Yes, 1.0, &mut c).unwrap();
+    assert_eq!(c.layout, Layout::ColMajor);
+    assert_eq!(c, ops::add(&expected, &Matrix::new(1, 1, vec![1.0])).unwrap());
+}
+
+#[test]
+fn test_solvers_accept_col_major() {
+    let a = Matrix::new(3, 3, vec![
+        4.0, 1.0, 2.0,
+        0.0, 3.0, 1.0,
+        0.0, 0.0, 2.0
+    ]);
+    let full = Matrix::new(3, 3, vec![
+        2.0, 1.0, 1.0,
+        1.0, 3.0, 2.0,
+        1.0, 0.0, 0.0
+    ]);
+    let b = Matrix::new(3, 1, vec![1.0, 2.0, 3.0]);
+
+    for matrix in [&a, &full] {
+        let wrapped = matrix.to_layout(Layout::ColMajor);
+        assert_eq!(ops::solve(&wrapped, &b).unwrap(), ops::solve(matrix, &b).unwrap());
+        assert_eq!(ops::determinant(&wrapped).unwrap(), ops::determinant(matrix).unwrap());
+        assert_eq!(ops::inv(&wrapped).unwrap(), ops::inv(matrix).unwrap());
+    }
+}
@@ -1,11 +1,11 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 
-use linear_algebra_ml::matrix::matrix::Matrix;
+use linear_algebra_ml::matrix::matrix::{Layout, Matrix};
 use linear_algebra_ml::{Sparse, Dense, Vector};
 
 #[test]
 fn test_sparse_matrix_macro() {
-    let dense = Sparse!(3, 3, [
+    let sparse = Sparse!(3, 3, [
         0, 0, 1.0,
         1, 2, 5.0,
         2, 1, 3.0
@@ -19,9 +19,11 @@ fn test_sparse_matrix_macro() {
             0.0, 0.0, 5.0,
             0.0, 3.0, 0.0,
         ],
+        layout: Layout::RowMajor,
     };
 
-    assert_eq!(dense.data, expected.data);
+    assert_eq!(sparse.nnz(), 3);
+    assert_eq!(sparse.to_dense().data, expected.data);
 }
 
 
@@ -37,6 +39,7 @@ fn test_dense_matrix_macro() {
         rows: 3,
         cols: 3,    
         data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+        layout: Layout::RowMajor,
     };
 
     assert_eq!(dense.data, expected.data);
@@ -52,6 +55,7 @@ fn test_vector_macro() {
         rows: 1,
         cols: 3,    
         data: vec![1.0, 2.0, 3.0],
+        layout: Layout::RowMajor,
     };
 
     assert_eq!(dense.data, expected.data);
@@ -2,6 +2,9 @@
 
 use linear_algebra_ml::matrix::matrix::Matrix;
 use linear_algebra_ml::matrix::ops;
+use linear_algebra_ml::matrix::decompositions;
+use linear_algebra_ml::utils::complex::Complex;
+use linear_algebra_ml::utils::error::Error;
 
 
 #[test]
@@ -22,7 +25,7 @@ fn test_matrix_new() {
 
 #[test]
 fn test_matrix_zeros() {
-    let matrix = Matrix::zeros(2, 3);
+    let matrix = Matrix::<f64>::zeros(2, 3);
     assert_eq!(matrix.rows, 2);
     assert_eq!(matrix.cols, 3);
 
@@ -35,7 +38,7 @@ fn test_matrix_zeros() {
 
 #[test]
 fn test_matrix_identity() {
-    let matrix = Matrix::identity(3);
+    let matrix = Matrix::<f64>::identity(3);
     assert_eq!(matrix.rows, 3);
     assert_eq!(matrix.cols, 3);
 
@@ -59,6 +62,51 @@ fn test_matrix_get() {
     assert_eq!(matrix.get(1, 1), 4.0);
 }
 
+#[test]
+fn test_matrix_try_new() {
+    assert_eq!(Matrix::try_new(2, 2, vec![1.0, 2.0, 3.0]).unwrap_err(), Error::DataLength {
+        shape: (2, 2),
+        len: 3,
+    });
+    assert!(Matrix::try_from_col_major(3, 1, vec![1.0; 4]).is_err());
+    assert_eq!(Matrix::try_new(1, 2, vec![1.0, 2.0]).unwrap(), Matrix::new(1, 2, vec![1.0, 2.0]));
+
+    // rows * cols overflows, which the error message must survive
+    let overflow = Matrix::<f64>::try_new(usize::MAX, 2, vec![]).unwrap_err();
+    assert_eq!(overflow, Error::DataLength { shape: (usize::MAX, 2), len: 0 });
+    assert_eq!(overflow.to_string(), format!("a {}x2 matrix needs more than usize::MAX elements, got 0", usize::MAX));
+}
+
+#[test]
+#[should_panic(expected = "Matrix::new: a 2x3 matrix needs 6 elements, got 5")]
+fn test_matrix_new_rejects_wrong_length() {
+    Matrix::new(2, 3, vec![1.0; 5]);
+}
+
+#[test]
+fn test_matrix_checked_access() {
+    let mut matrix = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        3.0, 4.0
+    ]);
+    let out_of_bounds = Err(Error::IndexOutOfBounds { index: (0, 2), shape: (2, 2) });
+
+    assert_eq!(matrix.try_get(1, 0), Ok(3.0));
+    // (0, 2) would alias (1, 0) in the row-major buffer
+    assert_eq!(matrix.try_get(0, 2), out_of_bounds);
+    assert_eq!(matrix.try_set(0, 2, 9.0), Err(Error::IndexOutOfBounds { index: (0, 2), shape: (2, 2) }));
+    assert_eq!(matrix.try_set(1, 1, 9.0), Ok(()));
+    assert_eq!(matrix.get(1, 0), 3.0);
+    assert_eq!(unsafe { matrix.get_unchecked(1, 1) }, 9.0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "index (0, 2) out of bounds for a 2x2 matrix")]
+fn test_matrix_get_debug_asserts_bounds() {
+    Matrix::<f64>::zeros(2, 2).get(0, 2);
+}
+
 #[test]
 fn test_matrix_set() {
     let mut matrix = Matrix::zeros(2, 3);
@@ -94,6 +142,55 @@ fn test_matrix_add() {
     ]);
 }
 
+#[test]
+fn test_matrix_add_broadcast() {
+    let a = Matrix::new(2, 3, vec![
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0
+    ]);
+    let row = Matrix::new(1, 3, vec![
+        10.0, 20.0, 30.0
+    ]);
+    let col = Matrix::new(2, 1, vec![
+        100.0,
+        200.0
+    ]);
+
+    assert_eq!(ops::add(&a, &row).unwrap().data, vec![
+        11.0, 22.0, 33.0,
+        14.0, 25.0, 36.0
+    ]);
+    assert_eq!(ops::sub(&col, &a).unwrap().data, vec![
+        99.0,  98.0,  97.0,
+        196.0, 195.0, 194.0
+    ]);
+    assert_eq!(ops::div(&a, &Matrix::new(1, 1, vec![2.0])).unwrap().data, vec![
+        0.5, 1.0, 1.5,
+        2.0, 2.5, 3.0
+    ]);
+
+    // a row against a column spreads to the outer shape
+    let outer = ops::hadamard(&col, &row).unwrap();
+    assert_eq!((outer.rows, outer.cols), (2, 3));
+    assert_eq!(outer.data, vec![
+        1000.0, 2000.0, 3000.0,
+        2000.0, 4000.0, 6000.0
+    ]);
+}
+
+#[test]
+fn test_matrix_add_broadcast_mismatch() {
+    let a = Matrix::<f64>::zeros(2, 3);
+    let b = Matrix::zeros(3, 1);
+
+    match ops::add(&a, &b) {
+        Err(Error::DimensionMismatch { op, left, right }) => {
+            assert_eq!((op, left, right), ("add", (2, 3), (3, 1)));
+        }
+        other => panic!("expected a size mismatch, got {:?}", other),
+    }
+}
+
 #[test]
 fn test_matrix_mul() {
     let a = Matrix::new(2, 3, vec![
@@ -230,34 +327,260 @@ fn test_matrix_inv() {
         1.5, -0.5   
     ]);
 
-    assert_eq!(result, expected);
+    assert_close(&result, &expected, 1e-14);
+}
+
+#[test]
+fn test_matrix_inv_keeps_precision() {
+    // entries of the inverse are far below the old 1e-6 rounding step
+    let a: Matrix = Matrix::new(2, 2, vec![
+        1e7, 0.0,
+        0.0, 3e7
+    ]);
+
+    let result = ops::inv(&a).unwrap();
+
+    assert_eq!(result.get(0, 0), 1e-7);
+    assert!((result.get(1, 1) - 1.0 / 3e7).abs() < 1e-22);
+}
+
+#[test]
+fn test_matrix_inv_condition() {
+    let a = Matrix::new(2, 2, vec![
+        1.0, 1.0,
+        1.0, 1.0 + 1e-10
+    ]);
+
+    let (_, cond) = ops::inv_with_condition(&a, 0.0).unwrap();
+    assert!(cond > 1e9);
+    assert!(ops::inv_with_condition(&a, 1e-8).is_err());
+    assert!(ops::inv(&Matrix::<f64>::zeros(2, 2)).is_err());
+    assert!(ops::inv(&Matrix::<f64>::zeros(2, 3)).is_err());
 }
 
 
 #[test]
 fn test_matrix_eigenvalues() {
-    let a = Matrix::new(2, 2, vec![
+    let a: Matrix = Matrix::new(2, 2, vec![
         1.0, 2.0,  
         3.0, 4.0   
     ]);
 
-    let result = ops::eigenvalues(&a);
+    let result = ops::eigenvalues(&a).unwrap();
 
-    assert_eq!(result, vec![5.372281323269014, -0.3722813232690143]);
+    let expected = [5.372281323269014, -0.3722813232690143];
+    for (value, expected) in result.iter().zip(expected) {
+        assert!((value.re - expected).abs() < 1e-12);
+        assert_eq!(value.im, 0.0);
+    }
+}
+
+#[test]
+fn test_matrix_eigenvalues_complex() {
+    // rotation by 90 degrees scaled by 2
+    let a: Matrix = Matrix::new(2, 2, vec![
+        0.0, -2.0,
+        2.0, 0.0
+    ]);
+
+    let result = ops::eigenvalues(&a).unwrap();
+
+    assert!((result[0].re).abs() < 1e-12 && (result[0].im - 2.0).abs() < 1e-12);
+    assert!((result[1].re).abs() < 1e-12 && (result[1].im + 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_matrix_eigenvalues_not_square() {
+    let a = Matrix::<f64>::zeros(2, 3);
+    assert!(ops::eigenvalues(&a).is_err());
 }
 
 #[test]
 fn test_matrix_det() {
-    let a = Matrix::new(2, 2, vec![
+    let a: Matrix = Matrix::new(2, 2, vec![
         1.0, 2.0,  
         3.0, 4.0   
     ]);
 
-    let result = ops::determinant(&a);
+    let result = ops::determinant(&a).unwrap();
 
-    assert_eq!(result, -2.0);
+    assert!((result + 2.0).abs() < 1e-12);
 }
 
+#[test]
+fn test_matrix_det_large() {
+    // 40x40 would take forever with cofactor expansion
+    let n = 40;
+    let mut a = Matrix::identity(n);
+    for i in 0..n {
+        a.set(i, i, 2.0);
+        a.set(i, (i + 1) % n, 1.0);
+    }
+
+    // det(2I + P) for a cyclic shift P of even order is 2^n - 1
+    let result = ops::determinant(&a).unwrap();
+    assert!((result / (2f64.powi(40) - 1.0) - 1.0).abs() < 1e-12);
+    assert!(ops::determinant(&Matrix::<f64>::zeros(2, 3)).is_err());
+}
+
+#[test]
+fn test_matrix_log_abs_det() {
+    // the determinant itself overflows to -inf
+    let a = Matrix::new(3, 3, vec![
+        -1e200, 0.0, 0.0,
+        0.0, 1e200, 0.0,
+        0.0, 0.0, 1e200
+    ]);
+
+    let (sign, log_abs) = ops::log_abs_determinant(&a).unwrap();
+    assert_eq!(sign, -1.0);
+    assert!((log_abs - 600.0 * 10f64.ln()).abs() < 1e-9);
+    assert!(ops::determinant(&a).unwrap().is_infinite());
+
+    let (sign, log_abs) = ops::log_abs_determinant(&Matrix::<f64>::zeros(2, 2)).unwrap();
+    assert_eq!(sign, 0.0);
+    assert_eq!(log_abs, f64::NEG_INFINITY);
+}
+
+
+fn assert_close(a: &Matrix, b: &Matrix, tol: f64) {
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+    for (x, y) in a.data.iter().zip(&b.data) {
+        assert!((x - y).abs() < tol, "{} != {}", x, y);
+    }
+}
+
+#[test]
+fn test_solve_square() {
+    let a = Matrix::new(3, 3, vec![
+        0.0, 2.0, 1.0,
+        1.0, 1.0, 0.0,
+        3.0, 0.0, 1.0
+    ]);
+    let b = Matrix::new(3, 1, vec![3.0, 2.0, 4.0]);
+
+    let x = ops::solve(&a, &b).unwrap();
+    assert_close(&x, &Matrix::new(3, 1, vec![1.0, 1.0, 1.0]), 1e-12);
+}
+
+#[test]
+fn test_solve_triangular_and_spd() {
+    let upper = Matrix::new(2, 2, vec![
+        2.0, 1.0,
+        0.0, 4.0
+    ]);
+    let b = Matrix::new(2, 1, vec![4.0, 8.0]);
+    assert_close(&ops::solve(&upper, &b).unwrap(), &Matrix::new(2, 1, vec![1.0, 2.0]), 1e-12);
+
+    let spd = Matrix::new(2, 2, vec![
+        4.0, 2.0,
+        2.0, 3.0
+    ]);
+    let x = ops::solve(&spd, &b).unwrap();
+    assert_close(&ops::mul(&spd, &x).unwrap(), &b, 1e-12);
+
+    let singular = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        2.0, 4.0
+    ]);
+    assert!(ops::solve(&singular, &b).is_err());
+
+    // a pivot that is rounding noise next to the largest one
+    let nearly = Matrix::new(2, 2, vec![
+        1.0, 1.0,
+        0.0, 1e-17
+    ]);
+    assert_eq!(ops::solve(&nearly, &b), Err(Error::SingularMatrix { pivot: 1 }));
+}
+
+#[test]
+fn test_solve_rectangular() {
+    // overdetermined, consistent: y = 1 + 2x
+    let a = Matrix::new(3, 2, vec![
+        1.0, 0.0,
+        1.0, 1.0,
+        1.0, 2.0
+    ]);
+    let b = Matrix::new(3, 1, vec![1.0, 3.0, 5.0]);
+    assert_close(&ops::solve(&a, &b).unwrap(), &Matrix::new(2, 1, vec![1.0, 2.0]), 1e-12);
+
+    // underdetermined: x + y = 2 has minimum-norm solution (1, 1)
+    let a = Matrix::new(1, 2, vec![1.0, 1.0]);
+    let b = Matrix::new(1, 1, vec![2.0]);
+    assert_close(&ops::solve(&a, &b).unwrap(), &Matrix::new(2, 1, vec![1.0, 1.0]), 1e-12);
+
+    // the third row is the sum of the first two, so R of A^T is singular up to rounding
+    let a = Matrix::new(3, 4, vec![
+        1.0, 0.0, 2.0, 1.0,
+        0.0, 1.0, 1.0, 3.0,
+        1.0, 1.0, 3.0, 4.0
+    ]);
+    assert_eq!(ops::solve(&a, &Matrix::new(3, 1, vec![1.0; 3])), Err(Error::SingularMatrix { pivot: 2 }));
+    let a = Matrix::new(2, 3, vec![
+        1e-20, 0.0, 0.0,
+        0.0,   1.0, 0.0
+    ]);
+    assert_eq!(ops::solve(&a, &Matrix::new(2, 1, vec![1.0; 2])), Err(Error::SingularMatrix { pivot: 0 }));
+}
+
+#[test]
+fn test_lstsq() {
+    let a = Matrix::new(4, 2, vec![
+        1.0, 0.0,
+        1.0, 1.0,
+        1.0, 2.0,
+        1.0, 3.0
+    ]);
+    let b = Matrix::new(4, 1, vec![1.0, 2.0, 2.0, 4.0]);
+
+    let (x, residuals, rank, s) = ops::lstsq(&a, &b, None).unwrap();
+    assert_eq!(rank, 2);
+    assert_eq!(s.len(), 2);
+    assert_close(&x, &Matrix::new(2, 1, vec![0.9, 0.9]), 1e-12);
+    assert!((residuals[0] - 0.7).abs() < 1e-12);
+
+    // duplicated column: rank deficient, no residuals reported
+    let a = Matrix::new(3, 2, vec![
+        1.0, 1.0,
+        2.0, 2.0,
+        3.0, 3.0
+    ]);
+    let b = Matrix::new(3, 1, vec![2.0, 4.0, 6.0]);
+    let (x, residuals, rank, _) = ops::lstsq(&a, &b, None).unwrap();
+    assert_eq!(rank, 1);
+    assert!(residuals.is_empty());
+    assert_close(&x, &Matrix::new(2, 1, vec![1.0, 1.0]), 1e-12);
+}
+
+#[test]
+fn test_solve_f32() {
+    let a: Matrix<f32> = Matrix::new(3, 3, vec![
+        2.0, 1.0, -1.0,
+        -3.0, -1.0, 2.0,
+        -2.0, 1.0, 2.0
+    ]);
+    let b = Matrix::new(3, 1, vec![8.0, -11.0, -3.0]);
+
+    let x = ops::solve(&a, &b).unwrap();
+    for (value, expected) in x.data.iter().zip([2.0, 3.0, -1.0]) {
+        assert!((value - expected).abs() < 1e-5);
+    }
+    assert!((ops::determinant(&a).unwrap() + 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_complex_mul() {
+    let i = Complex::new(0.0, 1.0);
+    let a = Matrix::new(2, 2, vec![
+        Complex::from(1.0), i,
+        -i, Complex::from(1.0)
+    ]);
+
+    // a is Hermitian, a * a = 2 * a
+    let result = ops::mul(&a, &a).unwrap();
+    assert_eq!(result, ops::scalar_mul(&a, Complex::from(2.0)));
+    assert_eq!(ops::transpose(&a).get(0, 1), -i);
+}
 
 /* VECTOR TESTS */
 
@@ -272,7 +595,7 @@ fn test_vector_dot() {
         3.0, 4.0   
     ]);
 
-    let result = ops::dot(&a, &b);
+    let result = ops::dot(&a, &b).unwrap();
 
     assert_eq!(result, 11.0);
 }
@@ -281,7 +604,7 @@ fn test_vector_dot() {
 fn test_vec_dot_product() {
     let a = Matrix::new(1, 3, vec![1.0, 2.0, 3.0]);
     let b = Matrix::new(3, 1, vec![4.0, 5.0, 6.0]);
-    assert_eq!(ops::dot(&a, &b), 32.0);
+    assert_eq!(ops::dot(&a, &b).unwrap(), 32.0);
 }
 
 #[test]
@@ -289,7 +612,7 @@ fn test_cross_product() {
     let a = Matrix::new(3, 1, vec![1.0, 2.0, 3.0]);
     let b = Matrix::new(3, 1, vec![4.0, 5.0, 6.0]);
     let expected = Matrix::new(3, 1, vec![-3.0, 6.0, -3.0]);
-    assert_eq!(ops::cross(&a, &b), expected);
+    assert_eq!(ops::cross(&a, &b).unwrap(), expected);
 }
 
 #[test]
@@ -297,13 +620,13 @@ fn test_projection() {
     let a = Matrix::new(1, 3, vec![3.0, 4.0, 0.0]);
     let b = Matrix::new(1, 3, vec![6.0, 8.0, 0.0]); 
     let expected = Matrix::new(1, 3, vec![3.0, 4.0, 0.0]);
-    assert_eq!(ops::projection(&a, &b), expected);
+    assert_eq!(ops::projection(&a, &b).unwrap(), expected);
 }
 
 #[test]
 fn test_normalize() {
-    let a = Matrix::new(1, 2, vec![3.0, 4.0]);
-    let normalized = ops::normalize(&a);
+    let a: Matrix = Matrix::new(1, 2, vec![3.0, 4.0]);
+    let normalized = ops::normalize(&a).unwrap();
     let expected = Matrix::new(1, 2, vec![0.6, 0.8]);
     for i in 0..normalized.data.len() {
         assert!((normalized.data[i] - expected.data[i]).abs() < 1e-6);
@@ -314,6 +637,67 @@ fn test_normalize() {
 fn test_angle_between_vectors() {
     let a = Matrix::new(1, 3, vec![1.0, 0.0, 0.0]); 
     let b = Matrix::new(1, 3, vec![0.0, 1.0, 0.0]); 
-    let angle = ops::angle(&a, &b);
+    let angle = ops::angle(&a, &b).unwrap();
     assert!((angle - std::f64::consts::FRAC_PI_2).abs() < 1e-6); 
+}
+
+#[test]
+fn test_vector_op_errors() {
+    let v = Matrix::new(1, 3, vec![1.0, 2.0, 3.0]);
+    let zero = Matrix::<f64>::zeros(1, 3);
+    let square = Matrix::<f64>::identity(2);
+
+    assert_eq!(ops::dot(&v, &square), Err(Error::NotAVector { op: "dot", shape: (2, 2) }));
+    assert_eq!(ops::dot(&v, &Matrix::new(2, 1, vec![1.0, 2.0])), Err(Error::DimensionMismatch {
+        op: "dot",
+        left: (1, 3),
+        right: (2, 1),
+    }));
+    assert_eq!(ops::cross(&v, &Matrix::new(1, 2, vec![1.0, 2.0])), Err(Error::VectorLength {
+        op: "cross",
+        expected: 3,
+        found: 2,
+    }));
+    assert_eq!(ops::normalize(&zero), Err(Error::ZeroVector { op: "normalize" }));
+    assert_eq!(ops::projection(&v, &zero), Err(Error::ZeroVector { op: "projection" }));
+    assert_eq!(ops::angle(&zero, &v), Err(Error::ZeroVector { op: "angle" }));
+    assert!(ops::magnitude(&square).is_err());
+}
+
+#[test]
+fn test_errors_carry_context() {
+    let singular = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        2.0, 4.0
+    ]);
+    let ill = Matrix::new(2, 2, vec![
+        1.0, 1.0,
+        1.0, 1.0 + 1e-12
+    ]);
+    let asymmetric = Matrix::new(2, 2, vec![
+        4.0, 1.0,
+        2.0, 3.0
+    ]);
+    let indefinite = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        2.0, 1.0
+    ]);
+
+    assert_eq!(ops::inv(&singular), Err(Error::SingularMatrix { pivot: 1 }));
+    assert_eq!(ops::determinant(&Matrix::<f64>::zeros(2, 3)), Err(Error::MatrixNotSquare {
+        op: "lu",
+        shape: (2, 3),
+    }));
+    match ops::inv_with_condition(&ill, 1e-8) {
+        Err(Error::IllConditioned { rcond, tolerance }) => {
+            assert!(rcond < tolerance && tolerance == 1e-8);
+        }
+        other => panic!("expected IllConditioned, got {:?}", other),
+    }
+    assert!(matches!(
+        decompositions::cholesky(&asymmetric),
+        Err(Error::MatrixNotSymmetric { row: 1, col: 0, .. })
+    ));
+    assert_eq!(decompositions::cholesky(&indefinite), Err(Error::NotPositiveDefinite { pivot: 1 }));
+    assert!(Error::NotConverged { op: "svd", iters: 30 }.to_string().contains("30 iterations"));
 }
\ No newline at end of file
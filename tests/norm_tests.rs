@@ -0,0 +1,110 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::decompositions::{inverse_norm1_estimate, LuFactor};
+use linear_algebra_ml::matrix::matrix::Matrix;
+use linear_algebra_ml::matrix::norms;
+use linear_algebra_ml::matrix::ops;
+use linear_algebra_ml::utils::error::Error;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-12 * b.abs().max(1.0)
+}
+
+#[test]
+fn test_entrywise_and_induced_norms() {
+    let a = Matrix::new(2, 3, vec![
+        1.0, -2.0, 3.0,
+        -4.0, 5.0, -6.0
+    ]);
+
+    assert_eq!(norms::norm_1(&a), 9.0);
+    assert_eq!(norms::norm_inf(&a), 15.0);
+    assert_eq!(norms::norm_max(&a), 6.0);
+    assert!(close(norms::norm_fro(&a), 91.0f64.sqrt()));
+    // views and transposes
+    assert_eq!(norms::norm_1(&a.clone().transpose()), 15.0);
+    assert_eq!(norms::norm_inf(&a.slice(0..2, 0..2)), 9.0);
+
+    // NaN propagates instead of being skipped by the maximum
+    let with_nan = Matrix::new(2, 2, vec![
+        f64::NAN, 1.0,
+        2.0,      3.0
+    ]);
+    assert!(norms::norm_max(&with_nan).is_nan());
+    assert!(norms::norm_1(&with_nan).is_nan());
+    assert!(norms::norm_inf(&with_nan).is_nan());
+    assert!(norms::vector_norm(&Matrix::new(1, 2, vec![1.0, f64::NAN]), 2.0).unwrap().is_nan());
+}
+
+#[test]
+fn test_singular_value_norms() {
+    // singular values 3 and 2
+    let a = Matrix::new(2, 2, vec![
+        0.0, 2.0,
+        -3.0, 0.0
+    ]);
+
+    assert!(close(norms::norm_2(&a).unwrap(), 3.0));
+    assert!(close(norms::norm_nuclear(&a).unwrap(), 5.0));
+    assert!(close(norms::cond_2(&a).unwrap(), 1.5));
+    assert!(close(norms::norm_2(&Matrix::new(1, 2, vec![3.0, 4.0])).unwrap(), 5.0));
+
+    let singular = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        2.0, 4.0
+    ]);
+    assert!(norms::cond_2(&singular).unwrap() > 1e15);
+    assert_eq!(norms::cond_1_estimate(&singular), Ok(f64::INFINITY));
+    assert_eq!(norms::cond_2(&Matrix::<f64>::zeros(0, 0)), Ok(1.0));
+    assert_eq!(norms::cond_2(&Matrix::<f64>::zeros(2, 2)), Ok(f64::INFINITY));
+}
+
+#[test]
+fn test_vector_norms() {
+    let v = Matrix::new(1, 3, vec![3.0, -4.0, 12.0]);
+
+    assert_eq!(norms::vector_norm(&v, 1.0), Ok(19.0));
+    assert!(close(norms::vector_norm(&v, 2.0).unwrap(), 13.0));
+    assert!(close(norms::vector_norm(&v, 3.0).unwrap(), (27.0f64 + 64.0 + 1728.0).cbrt()));
+    assert_eq!(norms::vector_norm(&v, f64::INFINITY), Ok(12.0));
+    assert_eq!(norms::vector_norm(&v.clone().transpose(), 2.0), Ok(ops::magnitude(&v).unwrap()));
+    // scaled so the squares do not overflow
+    assert!(close(norms::vector_norm(&Matrix::new(2, 1, vec![3e200, 4e200]), 2.0).unwrap(), 5e200));
+    assert_eq!(norms::vector_norm(&Matrix::<f64>::identity(2), 2.0), Err(Error::NotAVector {
+        op: "vector_norm",
+        shape: (2, 2),
+    }));
+    for p in [0.5, 0.0, -1.0, f64::NAN, f64::NEG_INFINITY] {
+        assert_eq!(norms::vector_norm(&v, p), Err(Error::InvalidArgument {
+            op: "vector_norm",
+            reason: "p must be at least 1",
+        }));
+    }
+}
+
+#[test]
+fn test_condition_estimate_tracks_exact() {
+    // Hilbert matrices get badly conditioned fast
+    for n in [3, 5, 8] {
+        let h = Matrix::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let exact = norms::norm_1(&h) * norms::norm_1(&ops::inv(&h).unwrap());
+        let estimate = norms::cond_1_estimate(&h).unwrap();
+
+        assert!(estimate <= exact * (1.0 + 1e-6));
+        assert!(estimate >= exact / 3.0);
+        assert_eq!(estimate, LuFactor::new(&h).unwrap().cond1_estimate());
+    }
+    assert_eq!(norms::cond_1_estimate(&Matrix::<f64>::identity(4)), Ok(1.0));
+}
+
+#[test]
+fn test_inverse_norm_estimate_with_custom_solver() {
+    // diagonal A: the solves just divide
+    let d = [2.0, 0.5, 4.0];
+    let estimate: f64 = inverse_norm1_estimate(
+        3,
+        |x| x.iter_mut().zip(&d).for_each(|(v, s)| *v /= s),
+        |x| x.iter_mut().zip(&d).for_each(|(v, s)| *v /= s),
+    );
+    assert_eq!(estimate, 2.0);
+}
@@ -0,0 +1,126 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::matrix::Matrix;
+use linear_algebra_ml::matrix::ops;
+use linear_algebra_ml::utils::complex::Complex;
+
+#[test]
+fn test_elementwise_operators() {
+    let a = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        3.0, 4.0
+    ]);
+    let b = Matrix::new(2, 2, vec![
+        5.0, 6.0,
+        7.0, 8.0
+    ]);
+
+    assert_eq!(&a + &b, ops::add(&a, &b).unwrap());
+    assert_eq!(a.clone() - &b, ops::sub(&a, &b).unwrap());
+    assert_eq!((-&a).data, vec![
+        -1.0, -2.0,
+        -3.0, -4.0
+    ]);
+
+    let mut c = a.clone();
+    c += &b;
+    c -= b.clone();
+    assert_eq!(c, a);
+}
+
+#[test]
+fn test_matrix_product_and_hadamard() {
+    let a = Matrix::new(2, 3, vec![
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0
+    ]);
+    let b = Matrix::new(3, 2, vec![
+        7.0,  8.0,
+        9.0,  10.0,
+        11.0, 12.0
+    ]);
+
+    assert_eq!((&a * &b).data, vec![
+        58.0,  64.0,
+        139.0, 154.0
+    ]);
+    assert_eq!(a.hadamard(&a).data, vec![
+        1.0,  4.0,  9.0,
+        16.0, 25.0, 36.0
+    ]);
+    assert!(ops::hadamard(&a, &b).is_err());
+
+    let mut c = a.clone();
+    c *= b;
+    assert_eq!((c.rows, c.cols), (2, 2));
+}
+
+#[test]
+fn test_scalar_operators() {
+    let a = Matrix::new(1, 3, vec![
+        1.0, 2.0, 4.0
+    ]);
+
+    assert_eq!((&a * 2.0).data, vec![2.0, 4.0, 8.0]);
+    assert_eq!((2.0_f64 * &a).data, vec![2.0, 4.0, 8.0]);
+    assert_eq!((&a / 2.0).data, vec![0.5, 1.0, 2.0]);
+    assert_eq!((a.clone() + 1.0).data, vec![2.0, 3.0, 5.0]);
+    assert_eq!((1.0_f64 - a).data, vec![0.0, -1.0, -3.0]);
+
+    let z = Matrix::new(1, 1, vec![Complex::new(1.0f32, 1.0)]);
+    assert_eq!((Complex::new(0.0, 1.0) * z).data, vec![Complex::new(-1.0, 1.0)]);
+}
+
+#[test]
+fn test_indexing() {
+    let mut a = Matrix::new(2, 3, vec![
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0
+    ]);
+    a[(1, 2)] = 9.0;
+
+    assert_eq!(a[(0, 1)], 2.0);
+    assert_eq!(a.get(1, 2), 9.0);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_index_out_of_bounds() {
+    let a = Matrix::<f64>::zeros(2, 3);
+    let _ = a[(0, 3)];
+}
+
+#[test]
+#[should_panic(expected = "matrix addition failed")]
+fn test_add_size_mismatch_panics() {
+    let _ = Matrix::<f64>::zeros(2, 2) + Matrix::zeros(2, 3);
+}
+
+#[test]
+fn test_operators_broadcast() {
+    let mut a = Matrix::new(2, 2, vec![
+        1.0, 2.0,
+        3.0, 4.0
+    ]);
+    let bias = Matrix::new(2, 1, vec![
+        10.0,
+        20.0
+    ]);
+
+    assert_eq!((&a + &bias).data, vec![
+        11.0, 12.0,
+        23.0, 24.0
+    ]);
+    a -= &bias;
+    assert_eq!(a.data, vec![
+        -9.0,  -8.0,
+        -17.0, -16.0
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "matrix addition failed")]
+fn test_add_assign_cannot_grow() {
+    let mut bias = Matrix::<f64>::zeros(2, 1);
+    bias += Matrix::zeros(2, 2);
+}
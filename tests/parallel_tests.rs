@@ -0,0 +1,105 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::decompositions;
+use linear_algebra_ml::matrix::matrix::Matrix;
+use linear_algebra_ml::matrix::ops;
+use linear_algebra_ml::utils::parallel;
+
+// deterministic, well-conditioned fill
+fn filled(rows: usize, cols: usize, seed: usize) -> Matrix {
+    let data = (0..rows * cols)
+        .map(|i| (((i * 37 + seed * 101) % 97) as f64 - 48.0) / 16.0)
+        .collect();
+    let mut matrix = Matrix::new(rows, cols, data);
+    for i in 0..rows.min(cols) {
+        matrix.set(i, i, matrix.get(i, i) + rows as f64);
+    }
+    matrix
+}
+
+fn assert_close(a: &Matrix, b: &Matrix, tol: f64) {
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+    for (x, y) in a.data.iter().zip(&b.data) {
+        assert!((x - y).abs() < tol, "{} vs {}", x, y);
+    }
+}
+
+// the thread settings are process-wide, so everything that changes them lives
+// in this one test
+#[test]
+fn test_thread_count_and_deterministic_reductions() {
+    let a = filled(300, 200, 1);
+    let b = filled(200, 250, 2);
+    let x = filled(1, 100_000, 3);
+    let y = filled(1, 100_000, 4);
+
+    parallel::set_num_threads(1);
+    assert_eq!(parallel::num_threads(), 1);
+    let serial_product = ops::mul(&a, &b).unwrap();
+    let serial_transpose = ops::transpose(&a);
+    let serial_sum = ops::add(&a, &a).unwrap();
+
+    parallel::set_deterministic(true);
+    assert!(parallel::is_deterministic());
+    let serial_dot = ops::dot(&x, &y).unwrap();
+
+    parallel::set_num_threads(4);
+    if cfg!(feature = "parallel") {
+        assert_eq!(parallel::num_threads(), 4);
+    } else {
+        assert_eq!(parallel::num_threads(), 1);
+    }
+    // the GEMM splits rows of C, never the inner dimension, so it is exact
+    assert_eq!(ops::mul(&a, &b).unwrap(), serial_product);
+    assert_eq!(ops::transpose(&a), serial_transpose);
+    assert_eq!(ops::add(&a, &a).unwrap(), serial_sum);
+    for _ in 0..3 {
+        assert_eq!(ops::dot(&x, &y).unwrap().to_bits(), serial_dot.to_bits());
+    }
+
+    parallel::set_deterministic(false);
+    assert!((ops::dot(&x, &y).unwrap() - serial_dot).abs() < 1e-6 * serial_dot.abs());
+
+    parallel::set_num_threads(0);
+    assert!(parallel::num_threads() >= 1);
+}
+
+#[test]
+fn test_blocked_lu_large() {
+    // more than two panels plus a ragged one
+    let n = 150;
+    let a = filled(n, n, 5);
+
+    let (l, u, perm, _) = decompositions::lu(&a).unwrap();
+    let mut permuted = Matrix::zeros(n, n);
+    for (i, &p) in perm.iter().enumerate() {
+        for j in 0..n {
+            permuted.set(i, j, a.get(p, j));
+        }
+    }
+    assert_close(&ops::mul(&l, &u).unwrap(), &permuted, 1e-9);
+
+    let x = filled(n, 1, 6);
+    let b = ops::mul(&a, &x).unwrap();
+    assert_close(&ops::solve(&a, &b).unwrap(), &x, 1e-9);
+}
+
+#[test]
+fn test_blocked_qr_large() {
+    let a = filled(200, 140, 7);
+
+    let (q, r) = decompositions::qr(&a).unwrap();
+    assert_close(&ops::mul(&q, &r).unwrap(), &a, 1e-9);
+    let qtq = ops::mul(&ops::transpose(&q), &q).unwrap();
+    assert_close(&qtq, &Matrix::identity(140), 1e-12);
+    for i in 0..r.rows {
+        for j in 0..i {
+            assert_eq!(r.get(i, j), 0.0);
+        }
+    }
+
+    // wide input: the last panel carries the extra columns
+    let a = filled(90, 160, 8);
+    let (q, r) = decompositions::qr(&a).unwrap();
+    assert_close(&ops::mul(&q, &r).unwrap(), &a, 1e-9);
+}
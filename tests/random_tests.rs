@@ -0,0 +1,130 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::matrix::Matrix;
+use linear_algebra_ml::matrix::ops;
+use linear_algebra_ml::utils::error::Error;
+use linear_algebra_ml::utils::random::{Rng, SplitMix64, Xoshiro256};
+
+fn mean_and_var(m: &Matrix) -> (f64, f64) {
+    let n = m.data.len() as f64;
+    let mean = m.data.iter().sum::<f64>() / n;
+    let var = m.data.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+    (mean, var)
+}
+
+// outputs of the reference C implementations
+#[test]
+fn test_generators_match_reference() {
+    assert_eq!(SplitMix64::new(0).next_u64(), 16294208416658607535);
+
+    let mut rng = Xoshiro256::new(42);
+    assert_eq!(
+        [rng.next_u64(), rng.next_u64(), rng.next_u64()],
+        [1546998764402558742, 6990951692964543102, 12544586762248559009]
+    );
+    assert_eq!(Xoshiro256::stream(42, 1).next_u64(), 5766981335298035530);
+}
+
+#[test]
+fn test_streams_replay() {
+    let draw = |seed, index| -> Matrix {
+        Matrix::random_normal(3, 4, 0.0, 1.0, &mut Xoshiro256::stream(seed, index))
+    };
+
+    assert_eq!(draw(7, 0), draw(7, 0));
+    assert_eq!(draw(7, 2), draw(7, 2));
+    assert_ne!(draw(7, 0), draw(7, 1));
+    assert_ne!(draw(7, 0), draw(8, 0));
+    assert_eq!(draw(7, 0), Matrix::random_normal(3, 4, 0.0, 1.0, &mut Xoshiro256::new(7)));
+}
+
+#[test]
+fn test_truncated_normal_stays_in_bounds() {
+    let mut rng = Xoshiro256::new(3);
+
+    // the usual +/- 2 std init
+    let m: Matrix = Matrix::random_truncated_normal(100, 100, 1.0, 0.5, 0.0, 2.0, &mut rng).unwrap();
+    assert!(m.data.iter().all(|&x| (0.0..=2.0).contains(&x)));
+    let (mean, var) = mean_and_var(&m);
+    assert!((mean - 1.0).abs() < 0.01);
+    // the variance of N(0, 1) truncated to [-2, 2] is about 0.774
+    assert!((var / 0.25 - 0.774).abs() < 0.02);
+
+    // far tail, one-sided and narrow intervals all terminate
+    let tail: Matrix = Matrix::random_truncated_normal(50, 50, 0.0, 1.0, 6.0, f64::INFINITY, &mut rng).unwrap();
+    assert!(tail.data.iter().all(|&x| x >= 6.0) && mean_and_var(&tail).0 < 6.3);
+    let left: Matrix = Matrix::random_truncated_normal(10, 10, 0.0, 1.0, -5.0, -4.99, &mut rng).unwrap();
+    assert!(left.data.iter().all(|&x| (-5.0..=-4.99).contains(&x)));
+    let centre: Matrix = Matrix::random_truncated_normal(10, 10, 0.0, 1.0, -0.1, 0.2, &mut rng).unwrap();
+    assert!(centre.data.iter().all(|&x| (-0.1..=0.2).contains(&x)));
+}
+
+#[test]
+fn test_bernoulli_and_dropout_masks() {
+    let mut rng = Xoshiro256::new(11);
+
+    let b: Matrix = Matrix::bernoulli(100, 100, 0.3, &mut rng).unwrap();
+    assert!(b.data.iter().all(|&x| x == 0.0 || x == 1.0));
+    assert!((mean_and_var(&b).0 - 0.3).abs() < 0.02);
+    assert_eq!(Matrix::<f64>::bernoulli(2, 2, 1.0, &mut rng).unwrap().data, vec![1.0; 4]);
+
+    let mask: Matrix = Matrix::dropout_mask(100, 100, 0.2, &mut rng).unwrap();
+    assert!(mask.data.iter().all(|&x| x == 0.0 || x == 1.25));
+    // inverted dropout keeps the expectation
+    assert!((mean_and_var(&mask).0 - 1.0).abs() < 0.03);
+    assert_eq!(Matrix::<f64>::dropout_mask(2, 2, 0.0, &mut rng).unwrap().data, vec![1.0; 4]);
+}
+
+#[test]
+fn test_random_constructors_reject_bad_arguments() {
+    let mut rng = Xoshiro256::new(13);
+    let truncated = |std, low, high, rng: &mut Xoshiro256| {
+        Matrix::<f64>::random_truncated_normal(2, 2, 0.0, std, low, high, rng).unwrap_err()
+    };
+    let bad_std = Error::InvalidArgument {
+        op: "random_truncated_normal",
+        reason: "mean must be finite and std finite and positive",
+    };
+    let bad_bounds = Error::InvalidArgument {
+        op: "random_truncated_normal",
+        reason: "low must be below high and neither may be NaN",
+    };
+
+    assert_eq!(truncated(0.0, -1.0, 1.0, &mut rng), bad_std);
+    assert_eq!(truncated(-1.0, -1.0, 1.0, &mut rng), bad_std);
+    assert_eq!(truncated(f64::NAN, -1.0, 1.0, &mut rng), bad_std);
+    assert_eq!(truncated(1.0, 1.0, 1.0, &mut rng), bad_bounds);
+    assert_eq!(truncated(1.0, 2.0, 1.0, &mut rng), bad_bounds);
+    assert_eq!(truncated(1.0, f64::NAN, 1.0, &mut rng), bad_bounds);
+    assert_eq!(truncated(1.0, -1.0, f64::NAN, &mut rng), bad_bounds);
+
+    for p in [-0.1, 1.1, f64::NAN] {
+        assert_eq!(Matrix::<f64>::bernoulli(2, 2, p, &mut rng), Err(Error::InvalidArgument {
+            op: "bernoulli",
+            reason: "p must lie in [0, 1]",
+        }));
+    }
+    for rate in [-0.1, 1.0, f64::NAN] {
+        assert_eq!(Matrix::<f64>::dropout_mask(2, 2, rate, &mut rng), Err(Error::InvalidArgument {
+            op: "dropout_mask",
+            reason: "rate must lie in [0, 1)",
+        }));
+    }
+}
+
+#[test]
+fn test_random_orthogonal() {
+    let mut rng = Xoshiro256::new(5);
+
+    for (rows, cols) in [(6, 6), (7, 3), (3, 7)] {
+        let q: Matrix = Matrix::random_orthogonal(rows, cols, &mut rng);
+        let gram = if rows >= cols {
+            ops::mul(&q.view().t(), &q).unwrap()
+        } else {
+            ops::mul(&q, &q.view().t()).unwrap()
+        };
+        let k = rows.min(cols);
+        assert_eq!(q.shape(), (rows, cols));
+        assert!(ops::sub(&gram, &Matrix::identity(k)).unwrap().data.iter().all(|x| x.abs() < 1e-12));
+    }
+}
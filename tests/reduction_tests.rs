@@ -0,0 +1,137 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::matrix::Matrix;
+use linear_algebra_ml::matrix::ops;
+use linear_algebra_ml::matrix::reductions::{self, Axis};
+use linear_algebra_ml::utils::error::Error;
+
+fn sample() -> Matrix {
+    Matrix::new(3, 2, vec![
+        1.0, 6.0,
+        4.0, 2.0,
+        7.0, 4.0
+    ])
+}
+
+#[test]
+fn test_total_reductions() {
+    let a = sample();
+
+    assert_eq!(reductions::sum(&a), 24.0);
+    assert_eq!(reductions::prod(&a), 1344.0);
+    assert_eq!(reductions::mean(&a), Ok(4.0));
+    assert_eq!(reductions::var(&a, 0), Ok(26.0 / 6.0));
+    assert_eq!(reductions::var(&a, 1), Ok(26.0 / 5.0));
+    assert_eq!(reductions::std(&a, 1), Ok((26.0f64 / 5.0).sqrt()));
+    assert_eq!(reductions::min(&a), Ok(1.0));
+    assert_eq!(reductions::max(&a), Ok(7.0));
+    assert_eq!(reductions::argmin(&a), Ok((0, 0)));
+    assert_eq!(reductions::argmax(&a), Ok((2, 0)));
+    assert_eq!(reductions::cumsum(&a).data, vec![1.0, 7.0, 11.0, 13.0, 20.0, 24.0]);
+}
+
+#[test]
+fn test_axis_reductions() {
+    let a = sample();
+
+    assert_eq!(reductions::sum_axis(&a, Axis::Rows), Matrix::new(1, 2, vec![12.0, 12.0]));
+    assert_eq!(reductions::sum_axis(&a, Axis::Cols), Matrix::new(3, 1, vec![7.0, 6.0, 11.0]));
+    assert_eq!(reductions::prod_axis(&a, Axis::Rows).data, vec![28.0, 48.0]);
+    assert_eq!(reductions::mean_axis(&a, Axis::Cols).unwrap().data, vec![3.5, 3.0, 5.5]);
+    assert_eq!(reductions::var_axis(&a, Axis::Rows, 1).unwrap().data, vec![9.0, 4.0]);
+    assert_eq!(reductions::std_axis(&a, Axis::Rows, 1).unwrap().data, vec![3.0, 2.0]);
+    assert_eq!(reductions::min_axis(&a, Axis::Rows).unwrap().data, vec![1.0, 2.0]);
+    assert_eq!(reductions::max_axis(&a, Axis::Cols).unwrap().data, vec![6.0, 4.0, 7.0]);
+    assert_eq!(reductions::argmin_axis(&a, Axis::Rows), Ok(vec![0, 1]));
+    assert_eq!(reductions::argmax_axis(&a, Axis::Cols), Ok(vec![1, 0, 0]));
+    assert_eq!(reductions::cumsum_axis(&a, Axis::Rows), Matrix::new(3, 2, vec![
+        1.0,  6.0,
+        5.0,  8.0,
+        12.0, 12.0
+    ]));
+    assert_eq!(reductions::cumsum_axis(&a, Axis::Cols), Matrix::new(3, 2, vec![
+        1.0, 7.0,
+        4.0, 6.0,
+        7.0, 11.0
+    ]));
+}
+
+#[test]
+fn test_reductions_on_views_and_layouts() {
+    let a = sample();
+    let wrapped = a.clone().transpose();
+
+    assert_eq!(reductions::sum_axis(&wrapped, Axis::Cols).data, vec![12.0, 12.0]);
+    assert_eq!(reductions::argmax(&wrapped), Ok((0, 2)));
+    assert_eq!(reductions::mean(&a.col(1)), Ok(4.0));
+    assert_eq!(reductions::max(&a.slice(1..3, 0..2)), Ok(7.0));
+}
+
+#[test]
+fn test_normalize_features() {
+    let a = sample();
+    let mean = reductions::mean_axis(&a, Axis::Rows).unwrap();
+    let std = reductions::std_axis(&a, Axis::Rows, 0).unwrap();
+    let z = ops::div(&ops::sub(&a, &mean).unwrap(), &std).unwrap();
+
+    for m in reductions::mean_axis(&z, Axis::Rows).unwrap().data {
+        assert!(m.abs() < 1e-12);
+    }
+    for v in reductions::var_axis(&z, Axis::Rows, 0).unwrap().data {
+        assert!((v - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_covariance_and_correlation() {
+    let a = sample();
+
+    assert_eq!(reductions::covariance(&a).unwrap(), Matrix::new(2, 2, vec![
+        9.0,  -3.0,
+        -3.0, 4.0
+    ]));
+    let corr = reductions::correlation(&a).unwrap();
+    assert_eq!(corr.get(0, 0), 1.0);
+    assert!((corr.get(0, 1) + 0.5).abs() < 1e-15);
+    assert_eq!(corr.get(0, 1), corr.get(1, 0));
+
+    // a constant column has no defined correlation
+    let constant: Matrix = Matrix::new(3, 2, vec![
+        1.0, 5.0,
+        2.0, 5.0,
+        3.0, 5.0
+    ]);
+    let corr = reductions::correlation(&constant).unwrap();
+    assert_eq!(corr.get(0, 0), 1.0);
+    assert!(corr.get(0, 1).is_nan() && corr.get(1, 1).is_nan());
+}
+
+#[test]
+fn test_reduction_errors_and_nan() {
+    let empty = Matrix::<f64>::zeros(0, 3);
+
+    assert_eq!(reductions::sum(&empty), 0.0);
+    assert_eq!(reductions::prod(&empty), 1.0);
+    assert_eq!(reductions::mean(&empty), Err(Error::TooFewElements { op: "mean", needed: 1, found: 0 }));
+    assert_eq!(reductions::var(&Matrix::new(1, 1, vec![2.0]), 1), Err(Error::TooFewElements {
+        op: "var",
+        needed: 2,
+        found: 1,
+    }));
+    assert_eq!(reductions::var(&sample(), usize::MAX), Err(Error::InvalidArgument {
+        op: "var",
+        reason: "ddof is too large",
+    }));
+    assert!(reductions::std_axis(&sample(), Axis::Rows, usize::MAX).is_err());
+    assert!(reductions::max_axis(&empty, Axis::Rows).is_err());
+    assert_eq!(reductions::sum_axis(&empty, Axis::Rows).data, vec![0.0; 3]);
+    assert_eq!(reductions::covariance(&Matrix::new(1, 2, vec![1.0, 2.0])), Err(Error::TooFewElements {
+        op: "covariance",
+        needed: 2,
+        found: 1,
+    }));
+
+    let with_nan = Matrix::new(1, 3, vec![1.0, f64::NAN, -1.0]);
+    assert!(reductions::min(&with_nan).unwrap().is_nan());
+    assert_eq!(reductions::argmax(&with_nan), Ok((0, 1)));
+}
@@ -0,0 +1,126 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::matrix::Matrix;
+use linear_algebra_ml::matrix::ops;
+use linear_algebra_ml::matrix::sparse::{CooMatrix, CscMatrix, CsrMatrix};
+use linear_algebra_ml::utils::error::Error;
+use linear_algebra_ml::Sparse;
+
+fn sample() -> Matrix {
+    Matrix::new(3, 4, vec![
+        1.0, 0.0, 0.0, 2.0,
+        0.0, 0.0, 3.0, 0.0,
+        4.0, 5.0, 0.0, 0.0
+    ])
+}
+
+#[test]
+fn test_sparse_round_trip() {
+    let dense = sample();
+
+    let csr = CsrMatrix::from_dense(&dense);
+    assert_eq!(csr.nnz(), 5);
+    assert_eq!(csr.row_offsets, vec![0, 2, 3, 5]);
+    assert_eq!(csr.col_indices, vec![0, 3, 2, 0, 1]);
+    assert_eq!(csr.to_dense(), dense);
+    assert_eq!(csr.get(2, 1), 5.0);
+    assert_eq!(csr.get(1, 1), 0.0);
+
+    let csc = CscMatrix::from_dense(&dense);
+    assert_eq!(csc.col_offsets, vec![0, 2, 3, 4, 5]);
+    assert_eq!(csc.row_indices, vec![0, 2, 2, 1, 0]);
+    assert_eq!(csc.to_dense(), dense);
+    assert_eq!(csc.get(0, 3), 2.0);
+
+    assert_eq!(csr.to_csc(), csc);
+    assert_eq!(csc.to_csr(), csr);
+    assert_eq!(csr.to_coo().to_csr(), csr);
+}
+
+#[test]
+fn test_coo_sums_duplicates() {
+    let mut coo = CooMatrix::new(2, 2);
+    coo.push(1, 0, 2.0).unwrap();
+    coo.push(0, 1, 1.0).unwrap();
+    coo.push(1, 0, 3.0).unwrap();
+    assert_eq!(coo.push(2, 0, 1.0), Err(Error::IndexOutOfBounds { index: (2, 0), shape: (2, 2) }));
+
+    let csr = coo.to_csr();
+    assert_eq!(csr.nnz(), 2);
+    assert_eq!(csr.get(1, 0), 5.0);
+    assert_eq!(coo.to_dense(), csr.to_dense());
+    assert_eq!(coo.to_csc().to_dense(), csr.to_dense());
+}
+
+#[test]
+fn test_sparse_transpose() {
+    let dense = sample();
+    let expected = ops::transpose(&dense);
+
+    assert_eq!(CsrMatrix::from_dense(&dense).transpose().to_dense(), expected);
+    assert_eq!(CscMatrix::from_dense(&dense).transpose().to_dense(), expected);
+}
+
+#[test]
+fn test_sparse_mul_dense() {
+    let dense = sample();
+    let b = Matrix::new(4, 2, vec![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0,
+        7.0, 8.0
+    ]);
+    let expected = ops::mul(&dense, &b).unwrap();
+
+    assert_eq!(CsrMatrix::from_dense(&dense).mul_dense(&b).unwrap(), expected);
+    assert_eq!(CscMatrix::from_dense(&dense).mul_dense(&b).unwrap(), expected);
+    assert!(CsrMatrix::from_dense(&dense).mul_dense(&dense).is_err());
+}
+
+#[test]
+fn test_sparse_mul_sparse() {
+    let a = sample();
+    let b = ops::transpose(&a);
+    let expected = ops::mul(&a, &b).unwrap();
+
+    let product = CsrMatrix::from_dense(&a).mul(&CsrMatrix::from_dense(&b)).unwrap();
+    assert_eq!(product.to_dense(), expected);
+    for i in 0..product.rows {
+        let row = &product.col_indices[product.row_offsets[i]..product.row_offsets[i + 1]];
+        assert!(row.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    let product = CscMatrix::from_dense(&a).mul(&CscMatrix::from_dense(&b)).unwrap();
+    assert_eq!(product.to_dense(), expected);
+    assert!(CsrMatrix::from_dense(&a).mul(&CsrMatrix::from_dense(&a)).is_err());
+    assert_eq!(CscMatrix::from_dense(&a).mul(&CscMatrix::from_dense(&a)), Err(Error::DimensionMismatch {
+        op: "sparse mul",
+        left: (3, 4),
+        right: (3, 4),
+    }));
+}
+
+#[test]
+fn test_sparse_add() {
+    let a = Sparse!(2, 3, [
+        0, 0, 1.0,
+        1, 2, 2.0
+    ]);
+    let b = Sparse!(2, 3, [
+        0, 0, 4.0,
+        0, 1, 5.0
+    ]);
+
+    let sum = a.add(&b).unwrap();
+    assert_eq!(sum.nnz(), 3);
+    assert_eq!(sum.to_dense(), ops::add(&a.to_dense(), &b.to_dense()).unwrap());
+    assert!(a.add(&a.transpose()).is_err());
+
+    let csc_sum = a.to_csc().add(&b.to_csc()).unwrap();
+    assert_eq!(csc_sum.to_csr(), sum);
+    assert_eq!(a.to_csc().add(&a.transpose().to_csc()), Err(Error::DimensionMismatch {
+        op: "sparse add",
+        left: (2, 3),
+        right: (3, 2),
+    }));
+}
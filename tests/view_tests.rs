@@ -0,0 +1,91 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use linear_algebra_ml::matrix::matrix::Matrix;
+use linear_algebra_ml::matrix::ops;
+
+fn sample() -> Matrix {
+    Matrix::new(3, 4, vec![
+        1.0, 2.0,  3.0,  4.0,
+        5.0, 6.0,  7.0,  8.0,
+        9.0, 10.0, 11.0, 12.0
+    ])
+}
+
+#[test]
+fn test_rows_cols_slices_and_diag() {
+    let a = sample();
+
+    assert_eq!(a.row(1).to_matrix().data, vec![5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(a.col(2).to_matrix().data, vec![3.0, 7.0, 11.0]);
+    assert_eq!(a.diag().to_matrix().data, vec![1.0, 6.0, 11.0]);
+
+    let block = a.slice(1..3, 1..3);
+    assert_eq!(block.shape(), (2, 2));
+    assert_eq!(block.to_matrix().data, vec![
+        6.0,  7.0,
+        10.0, 11.0
+    ]);
+    assert_eq!(block.col(1).get(1, 0), 11.0);
+    assert_eq!(block.t().get(0, 1), 10.0);
+
+    // rows are stored contiguously, columns and inner blocks are not
+    assert!(a.row(2).as_slice().is_some());
+    assert!(a.col(0).as_slice().is_none());
+    assert!(block.as_slice().is_none());
+}
+
+#[test]
+fn test_view_mut_writes_through() {
+    let mut a = sample();
+
+    a.col_mut(0).fill(0.0);
+    a.diag_mut().set(2, 0, -1.0);
+    a.slice_mut(0..2, 2..4).copy_from(&Matrix::identity(2)).unwrap();
+
+    assert_eq!(a.data, vec![
+        0.0, 2.0,  1.0,  0.0,
+        0.0, 6.0,  0.0,  1.0,
+        0.0, 10.0, -1.0, 12.0
+    ]);
+    assert!(a.row_mut(0).copy_from(&Matrix::<f64>::zeros(1, 3)).is_err());
+}
+
+#[test]
+fn test_ops_accept_views() {
+    let a = sample();
+    let b = Matrix::new(4, 2, vec![
+        1.0, 0.0,
+        0.0, 1.0,
+        1.0, 0.0,
+        0.0, 1.0
+    ]);
+
+    // a mini-batch of rows times a block of b, compared with copied operands
+    let batch = a.slice(0..2, 0..4);
+    let expected = ops::mul(&batch.to_matrix(), &b).unwrap();
+    assert_eq!(ops::mul(&batch, &b).unwrap(), expected);
+    // the block of b swaps the two columns
+    assert_eq!(ops::mul(&a.slice(0..2, 1..3), &b.slice(1..3, 0..2)).unwrap().data, vec![
+        3.0, 2.0,
+        7.0, 6.0
+    ]);
+
+    assert_eq!(ops::add(&a.col(3), &a.row(0)).unwrap().data, vec![
+        5.0,  6.0,  7.0,  8.0,
+        9.0,  10.0, 11.0, 12.0,
+        13.0, 14.0, 15.0, 16.0
+    ]);
+    assert_eq!(ops::transpose(&a.slice(0..2, 0..2)).data, vec![
+        1.0, 5.0,
+        2.0, 6.0
+    ]);
+    assert_eq!(ops::dot(&a.col(0), &a.row(0).slice(0..1, 1..4)).unwrap(), 1.0 * 2.0 + 5.0 * 3.0 + 9.0 * 4.0);
+    assert_eq!(ops::magnitude(&a.diag()).unwrap(), (1.0f64 + 36.0 + 121.0).sqrt());
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_slice_out_of_bounds() {
+    let a = sample();
+    let _ = a.slice(0..4, 0..1);
+}